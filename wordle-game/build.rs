@@ -0,0 +1,62 @@
+//! Packs the word lists in `data/` into 25-bit codes (5 bits per letter) so
+//! they don't have to be compiled in as string literals.
+use std::{env, fs, path::Path};
+
+const WORD_LENGTH: usize = 5;
+const BITS_PER_WORD: usize = 5 * WORD_LENGTH;
+
+const LISTS: &[(&str, &str)] = &[
+    ("data/answers.txt", "answers.bin"),
+    ("data/guesses.txt", "guesses.bin"),
+];
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    for (source, packed) in LISTS {
+        println!("cargo:rerun-if-changed={}", source);
+        let text = fs::read_to_string(source)
+            .unwrap_or_else(|err| panic!("unable to read {}: {}", source, err));
+        let mut codes: Vec<u32> = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, word)| !word.is_empty() && !word.starts_with('#'))
+            .map(|(line, word)| {
+                encode(word).unwrap_or_else(|| {
+                    panic!(
+                        "{}:{}: {:?} is not {} letters a-z",
+                        source, line, word, WORD_LENGTH
+                    )
+                })
+            })
+            .collect();
+        // sorted codes are in alphabetical order and allow binary search
+        codes.sort_unstable();
+        codes.dedup();
+        fs::write(Path::new(&out_dir).join(packed), pack(&codes)).unwrap();
+    }
+}
+
+fn encode(word: &str) -> Option<u32> {
+    if word.len() != WORD_LENGTH || !word.bytes().all(|b| b.is_ascii_lowercase()) {
+        return None;
+    }
+    Some(
+        word.bytes()
+            .fold(0, |code, b| (code << 5) | (b - b'a') as u32),
+    )
+}
+
+/// A little-endian bit stream of codes, padded so every code can be read
+/// with a single 4 byte load.
+fn pack(codes: &[u32]) -> Vec<u8> {
+    let mut bytes = vec![0u8; (codes.len() * BITS_PER_WORD).div_ceil(8) + 3];
+    for (i, code) in codes.iter().enumerate() {
+        let bit = i * BITS_PER_WORD;
+        let shifted = code << (bit % 8);
+        for (j, byte) in shifted.to_le_bytes().iter().enumerate() {
+            bytes[bit / 8 + j] |= byte;
+        }
+    }
+    bytes
+}
@@ -0,0 +1,296 @@
+//! Reviews a played game turn by turn against a solver strategy.
+use crate::constraint;
+use crate::game::WordleGame;
+use crate::solver_strategy::entropy::{best_expected_information, expected_information};
+use crate::solver_strategy::letter_frequency::LetterFrequencyWordleSolver;
+use crate::solver_strategy::SolverStrategy;
+use crate::stats::GameScores;
+use crate::types::{GameCondition, GameRules, Guesses, WordleGameState};
+use crate::word_list::WordList;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TurnAnalysis {
+    pub guess: String,
+    pub candidates_before: usize,
+    pub candidates_after: usize,
+    pub information: f64,
+    /// Bits the guess was expected to reveal, with every candidate equally
+    /// likely.
+    pub expected_information: f64,
+    pub best_guess: String,
+    pub best_candidates_after: usize,
+    /// The most any legal guess was expected to reveal, not necessarily
+    /// `best_guess`.
+    pub best_expected_information: f64,
+}
+
+impl TurnAnalysis {
+    /// Expected bits short of the best legal guess, so never positive.
+    pub fn skill(&self) -> f64 {
+        self.expected_information - self.best_expected_information
+    }
+
+    pub fn luck(&self) -> f64 {
+        self.information - self.expected_information
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameAnalysis {
+    pub secret_word: String,
+    pub turns: Vec<TurnAnalysis>,
+}
+
+impl GameAnalysis {
+    pub fn scores(&self) -> GameScores {
+        GameScores {
+            skill: self.turns.iter().map(TurnAnalysis::skill).sum(),
+            luck: self.turns.iter().map(TurnAnalysis::luck).sum(),
+        }
+    }
+}
+
+pub fn analyze_game(
+    game: &WordleGame,
+    answers: &[&str],
+    guess_pool: &[&str],
+    strategy: &mut dyn SolverStrategy,
+) -> GameAnalysis {
+    let guesses = game.words_already_guessed();
+    let guesses: Vec<&str> = guesses.iter().map(String::as_str).collect();
+    analyze_guesses(
+        &guesses,
+        game.secret_word(),
+        game.rules(),
+        answers,
+        guess_pool,
+        strategy,
+    )
+}
+
+pub fn score_game(game: &WordleGame, word_list: &WordList) -> GameScores {
+    let guesses = game.words_already_guessed();
+    let guesses: Vec<&str> = guesses.iter().map(String::as_str).collect();
+    score_guesses(&guesses, game.secret_word(), game.rules(), word_list)
+}
+
+/// Scores are the same for every strategy, so the cheap letter frequency
+/// solver names the alternatives.
+pub fn score_guesses(
+    guesses: &[&str],
+    secret_word: &str,
+    rules: GameRules,
+    word_list: &WordList,
+) -> GameScores {
+    let answers = word_list.answer_refs();
+    let allowed = word_list.allowed_refs();
+    let mut strategy = LetterFrequencyWordleSolver::new(&allowed, &answers);
+    analyze_guesses(
+        guesses,
+        secret_word,
+        rules,
+        &answers,
+        &allowed,
+        &mut strategy,
+    )
+    .scores()
+}
+
+pub fn analyze_guesses(
+    guesses: &[&str],
+    secret_word: &str,
+    rules: GameRules,
+    answers: &[&str],
+    guess_pool: &[&str],
+    strategy: &mut dyn SolverStrategy,
+) -> GameAnalysis {
+    let mut history: Guesses = vec![];
+    let mut candidates: Vec<&str> = answers.to_vec();
+    let mut turns = vec![];
+    for guess in guesses {
+        let prior = vec![1.0 / candidates.len() as f64; candidates.len()];
+        let best_guess = strategy.next_guess(&WordleGameState {
+            guesses: &history,
+            condition: GameCondition::Playing,
+            rules,
+        });
+        let remaining_after = |guess: &str| {
+            let result = constraint::check_guess(guess, secret_word);
+            candidates
+                .iter()
+                .copied()
+                .filter(|word| constraint::word_satisfies_contraint(word, &result))
+                .collect::<Vec<&str>>()
+        };
+        let after = remaining_after(guess);
+        let best_candidates_after = remaining_after(&best_guess).len();
+        let legal_guesses = guess_pool
+            .iter()
+            .copied()
+            .filter(|word| !rules.hard_mode || constraint::is_hard_mode_legal(word, &history));
+        let best_expected_information = best_expected_information(
+            candidates
+                .iter()
+                .copied()
+                .chain(legal_guesses)
+                .chain([*guess]),
+            &candidates,
+        );
+        turns.push(TurnAnalysis {
+            guess: guess.to_string(),
+            candidates_before: candidates.len(),
+            candidates_after: after.len(),
+            information: information(candidates.len(), after.len()),
+            expected_information: expected_information(guess, &candidates, &prior),
+            best_expected_information,
+            best_guess,
+            best_candidates_after,
+        });
+        history.push(constraint::check_guess(guess, secret_word));
+        candidates = after;
+    }
+    GameAnalysis {
+        secret_word: secret_word.to_string(),
+        turns,
+    }
+}
+
+fn information(before: usize, after: usize) -> f64 {
+    if before == 0 || after == 0 {
+        return 0.0;
+    }
+    (before as f64 / after as f64).log2()
+}
+
+impl fmt::Display for GameAnalysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, turn) in self.turns.iter().enumerate() {
+            write!(
+                f,
+                "guess {} {}: {} -> {} words ({:.1} bits, skill {:+.1}, luck {:+.1})",
+                i + 1,
+                turn.guess,
+                turn.candidates_before,
+                turn.candidates_after,
+                turn.information,
+                turn.skill(),
+                turn.luck()
+            )?;
+            if turn.best_guess != turn.guess {
+                write!(
+                    f,
+                    "; {} would have left {}",
+                    turn.best_guess, turn.best_candidates_after
+                )?;
+            }
+            writeln!(f)?;
+        }
+        let scores = self.scores();
+        writeln!(
+            f,
+            "skill {:+.1} bits, luck {:+.1} bits",
+            scores.skill, scores.luck
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_analysis {
+    use super::{analyze_game, analyze_guesses};
+    use crate::game::WordleGame;
+    use crate::solver_strategy::SolverStrategy;
+    use crate::types::{GameRules, WordleGameState};
+
+    const ANSWERS: [&str; 5] = ["crate", "grate", "irate", "plate", "slate"];
+
+    struct AlwaysGuess(&'static str);
+
+    impl SolverStrategy for AlwaysGuess {
+        fn next_guess(&mut self, _game_state: &WordleGameState) -> String {
+            self.0.to_string()
+        }
+    }
+
+    #[test]
+    fn reports_candidates_and_alternatives_per_turn() {
+        let mut strategy = AlwaysGuess("gipsy");
+        let analysis = analyze_guesses(
+            &["crate", "grate", "irate"],
+            "irate",
+            GameRules::default(),
+            &ANSWERS,
+            &["gipsy"],
+            &mut strategy,
+        );
+        let first = &analysis.turns[0];
+        assert_eq!(first.candidates_before, 5);
+        assert_eq!(first.candidates_after, 2);
+        assert!((first.information - (2.5f64).log2()).abs() < 1e-9);
+        assert_eq!(first.best_guess, "gipsy");
+        assert_eq!(first.best_candidates_after, 1);
+        assert_eq!(analysis.turns[2].candidates_after, 1);
+        assert_eq!(
+            analysis.to_string().lines().next(),
+            Some(
+                "guess 1 crate: 5 -> 2 words (1.3 bits, skill -0.8, luck -0.2); \
+                 gipsy would have left 1"
+            )
+        );
+    }
+
+    #[test]
+    fn analyzes_played_games() {
+        let mut game = WordleGame::new(&ANSWERS, "plate");
+        game.make_guess("slate").unwrap();
+        game.make_guess("plate").unwrap();
+        let analysis = analyze_game(&game, &ANSWERS, &[], &mut AlwaysGuess("plate"));
+        assert_eq!(analysis.secret_word, "plate");
+        assert_eq!(analysis.turns.len(), 2);
+        assert_eq!(analysis.turns[1].best_candidates_after, 1);
+    }
+
+    #[test]
+    fn scores_skill_and_luck() {
+        let mut strategy = AlwaysGuess("gipsy");
+        let analysis = analyze_guesses(
+            &["crate"],
+            "crate",
+            GameRules::default(),
+            &ANSWERS,
+            &["gipsy"],
+            &mut strategy,
+        );
+        let turn = &analysis.turns[0];
+        // "crate" splits the answers into groups of 1, 2 and 2; "gipsy" tells
+        // them all apart
+        let expected = 0.2 * 5f64.log2() + 0.8 * (5f64 / 2.0).log2();
+        assert!((turn.expected_information - expected).abs() < 1e-9);
+        assert!((turn.skill() - (expected - 5f64.log2())).abs() < 1e-9);
+        assert!((turn.luck() - (5f64.log2() - expected)).abs() < 1e-9);
+        let scores = analysis.scores();
+        assert_eq!(scores.skill, turn.skill());
+        assert_eq!(scores.luck, turn.luck());
+    }
+
+    #[test]
+    fn measures_skill_against_the_best_legal_guess() {
+        // the strategy's "mound" reveals nothing, but "gipsy" was allowed
+        let analysis = analyze_guesses(
+            &["crate"],
+            "plate",
+            GameRules::default(),
+            &ANSWERS,
+            &["gipsy", "mound"],
+            &mut AlwaysGuess("mound"),
+        );
+        let turn = &analysis.turns[0];
+        assert_eq!(turn.best_guess, "mound");
+        assert!((turn.best_expected_information - 5f64.log2()).abs() < 1e-9);
+        assert!(turn.skill() < 0.0);
+    }
+}
@@ -0,0 +1,210 @@
+//! Challenges: a chosen secret and rules, packed into a code for a link.
+//! The secret is obfuscated so it can't be read off the link at a glance;
+//! it is not encrypted.
+use crate::game::WordleGame;
+use crate::types::GameRules;
+use crate::word_list::WordList;
+use std::{error::Error, fmt};
+
+const CHALLENGE_VERSION: &str = "v1";
+const KEY: &[u8] = b"simpl";
+pub const MAX_CHALLENGE_GUESSES: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Challenge {
+    pub secret_word: String,
+    pub rules: GameRules,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChallengeError {
+    Malformed,
+    UnsupportedVersion(String),
+    /// The secret isn't one of the answers. The word itself is left out so
+    /// the error doesn't give it away.
+    UnknownSecretWord,
+}
+
+impl fmt::Display for ChallengeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChallengeError::Malformed => write!(f, "malformed challenge"),
+            ChallengeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported challenge version {:?}", version)
+            }
+            ChallengeError::UnknownSecretWord => {
+                write!(f, "the challenge word is not in this word list")
+            }
+        }
+    }
+}
+
+impl Error for ChallengeError {}
+
+impl Challenge {
+    /// Encodes the challenge as `v1.<length>.<max guesses>.<flags>.<secret>`,
+    /// e.g. `v1.5.6.hard.10529edf2d`. The only flag is `hard`.
+    pub fn encode(&self) -> String {
+        format!(
+            "{}.{}.{}.{}.{}",
+            CHALLENGE_VERSION,
+            self.secret_word.chars().count(),
+            self.rules.max_guesses,
+            if self.rules.hard_mode { "hard" } else { "" },
+            obfuscate(self.secret_word.as_bytes())
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>()
+        )
+    }
+
+    pub fn decode(code: &str) -> Result<Self, ChallengeError> {
+        let fields: Vec<&str> = code.trim().split('.').collect();
+        match fields[0] {
+            CHALLENGE_VERSION => {}
+            version if version.starts_with('v') => {
+                return Err(ChallengeError::UnsupportedVersion(version.to_string()))
+            }
+            _ => return Err(ChallengeError::Malformed),
+        }
+        let (length, max_guesses, flags, secret) = match fields[1..] {
+            [length, max_guesses, flags, secret] => (length, max_guesses, flags, secret),
+            _ => return Err(ChallengeError::Malformed),
+        };
+        let length: usize = length.parse().map_err(|_| ChallengeError::Malformed)?;
+        let max_guesses: usize = max_guesses.parse().map_err(|_| ChallengeError::Malformed)?;
+        let hard_mode = match flags {
+            "" => false,
+            "hard" => true,
+            _ => return Err(ChallengeError::Malformed),
+        };
+        let bytes = (0..secret.len())
+            .step_by(2)
+            .map(|i| {
+                secret
+                    .get(i..i + 2)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or(ChallengeError::Malformed)?;
+        let secret_word =
+            String::from_utf8(obfuscate(&bytes)).map_err(|_| ChallengeError::Malformed)?;
+        if !(1..=MAX_CHALLENGE_GUESSES).contains(&max_guesses)
+            || length == 0
+            || secret_word.chars().count() != length
+        {
+            return Err(ChallengeError::Malformed);
+        }
+        Ok(Self {
+            secret_word,
+            rules: GameRules {
+                max_guesses,
+                hard_mode,
+            },
+        })
+    }
+
+    pub fn start(&self, word_list: &WordList) -> Result<WordleGame, ChallengeError> {
+        let secret_word = word_list
+            .language()
+            .normalize(&self.secret_word)
+            .filter(|word| word_list.answers().contains(word))
+            .ok_or(ChallengeError::UnknownSecretWord)?;
+        let mut game = WordleGame::from_word_list(word_list, &secret_word);
+        game.set_hard_mode(self.rules.hard_mode)
+            .and_then(|_| game.set_max_guesses(self.rules.max_guesses))
+            .map_err(|_| ChallengeError::Malformed)?;
+        Ok(game)
+    }
+}
+
+fn obfuscate(bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .zip(KEY.iter().cycle())
+        .enumerate()
+        .map(|(i, (byte, key))| byte ^ key ^ (i as u8).wrapping_mul(73))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_challenge {
+    use super::{Challenge, ChallengeError};
+    use crate::language::ENGLISH;
+    use crate::types::GameRules;
+
+    fn challenge(secret_word: &str, hard_mode: bool) -> Challenge {
+        Challenge {
+            secret_word: secret_word.to_string(),
+            rules: GameRules {
+                max_guesses: 4,
+                hard_mode,
+            },
+        }
+    }
+
+    #[test]
+    fn round_trips_without_revealing_the_secret() {
+        for challenge in [challenge("crate", true), challenge("sueño", false)] {
+            let code = challenge.encode();
+            assert!(!code.contains(&challenge.secret_word));
+            assert_eq!(Challenge::decode(&code), Ok(challenge));
+        }
+        assert!(challenge("crate", true)
+            .encode()
+            .starts_with("v1.5.4.hard."));
+        assert_eq!(challenge("crate", false).encode(), "v1.5.4..10529edf2d");
+    }
+
+    #[test]
+    fn rejects_tampered_codes() {
+        let code = challenge("crate", false).encode();
+        assert_eq!(
+            Challenge::decode(&code.replacen("v1.5", "v1.6", 1)),
+            Err(ChallengeError::Malformed)
+        );
+        assert_eq!(
+            Challenge::decode(&code[..code.len() - 1]),
+            Err(ChallengeError::Malformed)
+        );
+        assert_eq!(
+            Challenge::decode(&code.replacen("..", ".easy.", 1)),
+            Err(ChallengeError::Malformed)
+        );
+        assert_eq!(
+            Challenge::decode("v9.whatever"),
+            Err(ChallengeError::UnsupportedVersion("v9".to_string()))
+        );
+        assert_eq!(Challenge::decode("crate"), Err(ChallengeError::Malformed));
+    }
+
+    #[test]
+    fn rejects_out_of_range_guess_limits() {
+        let code = challenge("crate", false).encode();
+        for max_guesses in ["v1.5.0.", "v1.5.11.", "v1.5.99999999."] {
+            assert_eq!(
+                Challenge::decode(&code.replacen("v1.5.4.", max_guesses, 1)),
+                Err(ChallengeError::Malformed)
+            );
+        }
+        assert!(Challenge::decode(&code.replacen("v1.5.4.", "v1.5.10.", 1)).is_ok());
+    }
+
+    #[test]
+    fn starts_games_with_the_challenge_rules() {
+        let word_list = ENGLISH.word_list();
+        let game = challenge("CRATE", true).start(&word_list).unwrap();
+        assert_eq!(game.secret_word(), "crate");
+        assert_eq!(
+            game.rules(),
+            GameRules {
+                max_guesses: 4,
+                hard_mode: true
+            }
+        );
+        assert_eq!(
+            challenge("zzzzz", false).start(&word_list).err(),
+            Some(ChallengeError::UnknownSecretWord)
+        );
+    }
+}
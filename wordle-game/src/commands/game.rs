@@ -1,48 +1,209 @@
-use crate::dictionary::PICKABLE_WORDS;
+use super::review::print_review;
+use super::words::print_letter_frequencies;
+use crate::analysis::score_game;
+use crate::frequency::WordFrequencies;
 use crate::game::WordleGame;
-use crate::types::{GameCondition, Guess, LetterState};
+use crate::letter_frequency::LetterFrequencies;
+use crate::save::SavedGame;
+use crate::share::emoji_row;
+use crate::stats::{GameRecord, StatsStore};
+use crate::types::GameCondition;
+use crate::word_list::WordList;
+use std::error::Error;
+use std::fs;
 use std::io::{self, Write};
+use std::path::Path;
 
-pub fn run_cli_game() {
-    let dictionary: Vec<&str> = PICKABLE_WORDS.to_vec();
-    let mut game = WordleGame::new_with_random_secret_word(&dictionary);
+/// With `easy` new secrets are picked by word frequency. With `review`
+/// finished games are reviewed against that strategy.
+pub fn run_cli_game(
+    word_list: &WordList,
+    save_path: Option<&Path>,
+    practice: bool,
+    hard_mode: bool,
+    frequencies: Option<&WordFrequencies>,
+    easy: bool,
+    review: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let easy_frequencies = frequencies.filter(|_| easy);
+    let mut game = match save_path.filter(|path| path.exists()) {
+        Some(path) => resume_game(word_list, path)?,
+        None => new_game(word_list, easy_frequencies),
+    };
+    if practice {
+        game.enable_practice_mode();
+    }
+    if hard_mode {
+        enable_hard_mode(&mut game);
+    }
+    if game.rules().hard_mode {
+        println!("Hard mode: every guess must use the hints revealed so far");
+    }
+    if game.is_practice() {
+        println!(
+            "Practice mode: type :undo or :redo to step through guesses, :new for another word"
+        );
+    }
+    println!("Type :letters to see letter frequencies among the remaining answers, :quit to stop");
     loop {
         print!("Make guess: ");
         io::stdout().flush().expect("unable to flush stdout");
-        let input = get_line().expect("unable to read line");
-        if let Err(msg) = game.make_guess(&input) {
+        let input = match get_line()? {
+            Some(input) if input != ":quit" => input,
+            _ => {
+                if let Some(path) = save_path {
+                    fs::write(path, game.save().encode())?;
+                    println!("\nProgress saved to {}", path.display());
+                }
+                break;
+            }
+        };
+        if input == ":new" {
+            if !game.is_practice() && game.game_condition() == GameCondition::Playing {
+                println!("Finish this game first, or type :quit to save it for later");
+                continue;
+            }
+            let rules = game.rules();
+            game = new_game(word_list, easy_frequencies);
+            game.enable_practice_mode();
+            game.set_hard_mode(rules.hard_mode || hard_mode)?;
+            println!("New practice game");
+            continue;
+        }
+        if input == ":letters" {
+            print_letter_frequencies(&LetterFrequencies::from_candidates(
+                &word_list.answer_refs(),
+                &game.game_state(),
+            ));
+            continue;
+        }
+        let result = match input.as_str() {
+            ":undo" => game.undo(),
+            ":redo" => game.redo(),
+            guess => game.make_guess(guess),
+        };
+        if let Err(msg) = result {
             println!("{}", msg);
             continue;
         }
-        let game_state = game.game_state();
-        let guess_result = game_state.guesses.last().unwrap();
-        println!("{}", guess_colors(guess_result));
-        if game_state.condition == GameCondition::Win {
+        if input.starts_with(':') {
+            print_guesses(&game);
+        } else {
+            let guess_result = game.game_state().guesses.last().unwrap();
+            println!("{}", emoji_row(guess_result));
+        }
+        let condition = game.game_condition();
+        if condition == GameCondition::Playing || game.is_practice() {
+            if let Some(path) = save_path {
+                fs::write(path, game.save().encode())?;
+            }
+        }
+        if condition == GameCondition::Playing {
+            continue;
+        }
+        if condition == GameCondition::Win {
             println!("You Win!");
-            break;
-        } else if game_state.condition == GameCondition::Loss {
+        } else if condition == GameCondition::Loss {
             println!("You Lose!");
             println!("word was {}", game.secret_word());
-            break;
         }
+        if let Some(strategy_name) = review {
+            print_review(word_list, &game, strategy_name, frequencies)?;
+        }
+        // practice games stay open so guesses can still be undone
+        if game.is_practice() {
+            println!("Type :undo to step back, :new for another word or :quit to stop");
+            continue;
+        }
+        if let Some(path) = save_path.filter(|path| path.exists()) {
+            fs::remove_file(path)?;
+        }
+        record_result(word_list, &game);
+        break;
+    }
+    Ok(())
+}
+
+fn new_game(word_list: &WordList, easy_frequencies: Option<&WordFrequencies>) -> WordleGame {
+    match easy_frequencies {
+        Some(frequencies) => {
+            WordleGame::from_word_list_with_weighted_secret_word(word_list, frequencies)
+        }
+        None => WordleGame::from_word_list_with_random_secret_word(word_list),
+    }
+}
+
+fn resume_game(word_list: &WordList, path: &Path) -> Result<WordleGame, Box<dyn Error>> {
+    let saved = SavedGame::decode(&fs::read_to_string(path)?)?;
+    let game = WordleGame::restore_from_word_list(word_list, &saved)?;
+    println!("Resuming game from {}", path.display());
+    print_guesses(&game);
+    Ok(game)
+}
+
+/// A resumed game with guesses keeps its saved rules.
+fn enable_hard_mode(game: &mut WordleGame) {
+    if game.set_hard_mode(true).is_err() {
+        println!("Hard mode applies from the next game, this one keeps its saved rules");
+    }
+}
+
+fn print_guesses(game: &WordleGame) {
+    for guess in game.game_state().guesses {
+        let word: String = guess.iter().map(|(c, _)| c).collect();
+        println!("{} {}", word, emoji_row(guess));
     }
 }
 
-fn get_line() -> io::Result<String> {
+fn record_result(word_list: &WordList, game: &WordleGame) {
+    let scores = score_game(game, word_list);
+    println!(
+        "skill {:+.1} bits, luck {:+.1} bits",
+        scores.skill, scores.luck
+    );
+    let record = GameRecord {
+        secret_word: game.secret_word().to_string(),
+        num_guesses: game.game_state().guesses.len(),
+        result: game.game_condition(),
+        puzzle_number: None,
+        scores: Some(scores),
+    };
+    let recorded = super::stats::stats_store().and_then(|mut store| Ok(store.record(record)?));
+    if let Err(err) = recorded {
+        println!("unable to record stats: {}", err);
+    }
+}
+
+fn get_line() -> io::Result<Option<String>> {
     let mut input = String::new();
 
-    io::stdin().read_line(&mut input)?;
-    Ok(input.trim().to_owned())
+    if io::stdin().read_line(&mut input)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(input.trim().to_owned()))
 }
 
-fn guess_colors(letter_results: &Guess) -> String {
-    letter_results
-        .iter()
-        .map(|(_, r)| match r {
-            LetterState::CorrectPlacement => "🟩",
-            LetterState::CorrectLetter => "🟨",
-            LetterState::Incorrect => "⬛️",
-        })
-        .collect::<Vec<&str>>()
-        .join("")
+#[cfg(test)]
+mod test_enable_hard_mode {
+    use super::enable_hard_mode;
+    use crate::game::WordleGame;
+    use crate::language::ENGLISH;
+    use crate::save::SavedGame;
+
+    #[test]
+    fn resumed_games_keep_their_rules() {
+        let word_list = ENGLISH.word_list();
+        let mut game = WordleGame::from_word_list(&word_list, "crate");
+        game.make_guess("slate").unwrap();
+        let saved = SavedGame::decode(&game.save().encode()).unwrap();
+        let mut resumed = WordleGame::restore_from_word_list(&word_list, &saved).unwrap();
+
+        enable_hard_mode(&mut resumed);
+        assert!(!resumed.rules().hard_mode);
+        assert_eq!(resumed.words_already_guessed(), vec!["slate"]);
+
+        let mut fresh = WordleGame::from_word_list(&word_list, "crate");
+        enable_hard_mode(&mut fresh);
+        assert!(fresh.rules().hard_mode);
+    }
 }
@@ -0,0 +1,19 @@
+use crate::constraint::secrets_matching_grid;
+use crate::share::parse_emoji_grid;
+use crate::word_list::WordList;
+use std::error::Error;
+use std::io::{self, Read};
+
+pub fn run_grid_analysis(word_list: &WordList) -> Result<(), Box<dyn Error>> {
+    println!("Paste a shared result grid, then press Ctrl-D:");
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    let grid = parse_emoji_grid(&input)?;
+
+    let secrets = secrets_matching_grid(&grid, &word_list.answer_refs(), &word_list.allowed_refs());
+    println!("{} possible answers", secrets.len());
+    for secret in secrets {
+        println!("{}", secret);
+    }
+    Ok(())
+}
@@ -1,2 +1,27 @@
+use crate::frequency::{FrequencyError, WordFrequencies};
+use crate::language::Language;
+use crate::word_list::{WordList, WordListError};
+use std::path::Path;
+
 pub mod game;
+pub mod grid;
+pub mod openers;
+pub mod review;
 pub mod solver;
+pub mod stats;
+pub mod words;
+
+pub fn load_word_list(
+    language: &'static Language,
+    answers: Option<&Path>,
+    guesses: Option<&Path>,
+) -> Result<WordList, WordListError> {
+    match answers {
+        Some(answers) => WordList::from_files(language, answers, guesses),
+        None => Ok(language.word_list()),
+    }
+}
+
+pub fn load_frequencies(path: Option<&Path>) -> Result<Option<WordFrequencies>, FrequencyError> {
+    path.map(WordFrequencies::from_file).transpose()
+}
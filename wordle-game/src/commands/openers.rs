@@ -0,0 +1,76 @@
+use crate::frequency::WordFrequencies;
+use crate::openers::{rank_openers, score_openers, simulate, OpenerScore};
+use crate::solver_strategy::{build_strategy, lookahead::LookaheadConfig};
+use crate::word_list::WordList;
+use std::error::Error;
+
+pub const OPENER_ORDERS: &[&str] = &["entropy", "remaining", "worst-case"];
+
+/// Scores `openers` played in order, or ranks every allowed guess as an
+/// opener and prints the best `top`. With `simulate` the average guesses of
+/// that strategy after the openers is shown too.
+pub fn run_openers(
+    word_list: &WordList,
+    openers: &[String],
+    top: usize,
+    order: &str,
+    simulate_with: Option<&str>,
+    frequencies: Option<&WordFrequencies>,
+) -> Result<(), Box<dyn Error>> {
+    let answers = word_list.answer_refs();
+    let scores = if openers.is_empty() {
+        let mut scores = rank_openers(&word_list.allowed_refs(), &answers);
+        match order {
+            "remaining" => {
+                scores.sort_by(|a, b| a.expected_remaining.total_cmp(&b.expected_remaining))
+            }
+            "worst-case" => scores.sort_by_key(|score| score.worst_case),
+            _ => {}
+        }
+        scores.truncate(top);
+        scores
+    } else {
+        let openers = openers
+            .iter()
+            .map(|opener| {
+                let normalized = word_list.language().normalize(opener);
+                normalized
+                    .filter(|word| word_list.allowed().contains(word))
+                    .ok_or_else(|| format!("{:?} is not an allowed guess", opener))
+            })
+            .collect::<Result<Vec<String>, String>>()?;
+        let openers: Vec<&str> = openers.iter().map(String::as_str).collect();
+        vec![score_openers(&openers, &answers)]
+    };
+
+    let mut strategy = simulate_with
+        .map(|name| build_strategy(name, word_list, frequencies, &LookaheadConfig::default()))
+        .transpose()?;
+    println!(
+        "rank  openers      bits  remaining  worst{}",
+        if strategy.is_some() { "  average" } else { "" }
+    );
+    for (rank, score) in scores.iter().enumerate() {
+        print_score(rank + 1, score);
+        if let Some(strategy) = strategy.as_mut() {
+            let openers: Vec<&str> = score.openers.iter().map(String::as_str).collect();
+            print!(
+                "  {:>7.3}",
+                simulate(word_list, &openers, strategy.as_mut())
+            );
+        }
+        println!();
+    }
+    Ok(())
+}
+
+fn print_score(rank: usize, score: &OpenerScore) {
+    print!(
+        "{:>4}  {:<11} {:>5.3}  {:>9.1}  {:>5}",
+        rank,
+        score.openers.join(","),
+        score.entropy,
+        score.expected_remaining,
+        score.worst_case
+    );
+}
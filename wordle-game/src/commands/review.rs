@@ -0,0 +1,54 @@
+use crate::analysis::analyze_game;
+use crate::frequency::WordFrequencies;
+use crate::game::WordleGame;
+use crate::solver_strategy::{build_strategy, lookahead::LookaheadConfig};
+use crate::word_list::WordList;
+use std::error::Error;
+
+pub fn run_review(
+    word_list: &WordList,
+    secret_word: &str,
+    guesses: &[&str],
+    strategy_name: &str,
+    frequencies: Option<&WordFrequencies>,
+    hard_mode: bool,
+) -> Result<(), Box<dyn Error>> {
+    let secret_word = word_list
+        .language()
+        .normalize(secret_word)
+        .filter(|word| word_list.answers().contains(word))
+        .ok_or_else(|| format!("{:?} is not one of the answers", secret_word))?;
+    let mut game = WordleGame::from_word_list(word_list, &secret_word);
+    game.set_hard_mode(hard_mode)?;
+    for guess in guesses {
+        game.make_guess(guess)
+            .map_err(|reason| format!("{:?}: {}", guess, reason))?;
+    }
+    print_review(word_list, &game, strategy_name, frequencies)
+}
+
+/// Prints how each guess of `game` narrowed down the answers and what the
+/// named strategy would have guessed instead.
+pub fn print_review(
+    word_list: &WordList,
+    game: &WordleGame,
+    strategy_name: &str,
+    frequencies: Option<&WordFrequencies>,
+) -> Result<(), Box<dyn Error>> {
+    let mut strategy = build_strategy(
+        strategy_name,
+        word_list,
+        frequencies,
+        &LookaheadConfig::default(),
+    )?;
+    print!(
+        "{}",
+        analyze_game(
+            game,
+            &word_list.answer_refs(),
+            &word_list.allowed_refs(),
+            strategy.as_mut()
+        )
+    );
+    Ok(())
+}
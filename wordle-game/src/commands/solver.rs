@@ -1,18 +1,54 @@
-use crate::dictionary::PICKABLE_WORDS;
+use crate::frequency::WordFrequencies;
 use crate::game::WordleGame;
 use crate::solver::WordleSolver;
-use crate::solver_strategy::narrowing_random::NarrowingRandomWordleSolver;
+use crate::solver_strategy::lookahead::LookaheadConfig;
+use crate::solver_strategy::{build_strategy, SolverStrategy};
 use crate::types::GameCondition;
+use crate::word_list::WordList;
 
-pub fn run_solver() {
-    let dictionary: Vec<&str> = PICKABLE_WORDS.to_vec();
+/// In easy mode secrets are picked by word frequency.
+pub fn run_solver(
+    word_list: &WordList,
+    frequencies: Option<&WordFrequencies>,
+    strategy_name: &str,
+    games: usize,
+    easy: bool,
+    hard_modes: &[bool],
+    lookahead: &LookaheadConfig,
+) -> Result<(), String> {
+    let mut strategy = build_strategy(strategy_name, word_list, frequencies, lookahead)?;
+    println!("strategy: {strategy_name}");
+    for &hard_mode in hard_modes {
+        benchmark(
+            word_list,
+            strategy.as_mut(),
+            games,
+            frequencies.filter(|_| easy),
+            hard_mode,
+        );
+    }
+    Ok(())
+}
+
+fn benchmark(
+    word_list: &WordList,
+    strategy: &mut dyn SolverStrategy,
+    games: usize,
+    easy_frequencies: Option<&WordFrequencies>,
+    hard_mode: bool,
+) {
     let mut win_total = 0;
-    let games = 10000;
     let mut num_guesses = Vec::<usize>::with_capacity(games);
     for _ in 0..games {
-        let mut strategy = NarrowingRandomWordleSolver::new(&dictionary);
-        let mut game = WordleGame::new_with_random_secret_word(&dictionary);
-        let mut solver = WordleSolver::new(&mut game, &mut strategy);
+        let mut game = match easy_frequencies {
+            Some(frequencies) => {
+                WordleGame::from_word_list_with_weighted_secret_word(word_list, frequencies)
+            }
+            None => WordleGame::from_word_list_with_random_secret_word(word_list),
+        };
+        game.set_hard_mode(hard_mode)
+            .expect("no guesses have been made yet");
+        let mut solver = WordleSolver::new(&mut game, strategy);
         let result = solver.run_game();
         if result.result == GameCondition::Win {
             win_total += 1;
@@ -22,6 +58,7 @@ pub fn run_solver() {
     let win_percentage = (win_total as f64 / games as f64) * 100_f64;
     let sum_guesses: usize = num_guesses.iter().sum();
     let avg_guesses: f64 = sum_guesses as f64 / games as f64;
+    println!("mode: {}", if hard_mode { "hard" } else { "normal" });
     println!("win_total: {win_total} win percentage: {win_percentage}");
     println!("average_guesses: {avg_guesses}");
 }
@@ -0,0 +1,32 @@
+use crate::game::DEFAULT_MAX_GUESSES;
+use crate::stats::{FileStatsStore, StatsStore};
+use std::error::Error;
+
+pub fn stats_store() -> Result<FileStatsStore, Box<dyn Error>> {
+    let data_dir = dirs::data_dir().ok_or("unable to locate the user data directory")?;
+    Ok(FileStatsStore::new(
+        data_dir.join("simpl").join("stats.txt"),
+    ))
+}
+
+pub fn run_stats() -> Result<(), Box<dyn Error>> {
+    let mut stats = stats_store()?.stats()?;
+    if stats.guess_distribution.len() < DEFAULT_MAX_GUESSES {
+        stats.guess_distribution.resize(DEFAULT_MAX_GUESSES, 0);
+    }
+    println!("games played: {}", stats.games_played);
+    println!("win percentage: {:.0}", stats.win_percentage());
+    println!("current streak: {}", stats.current_streak);
+    println!("max streak: {}", stats.max_streak);
+    if let (Some(skill), Some(luck)) = (stats.average_skill(), stats.average_luck()) {
+        println!("average skill: {:+.2} bits", skill);
+        println!("average luck: {:+.2} bits", luck);
+    }
+    println!("guess distribution:");
+    let most_common = stats.guess_distribution.iter().max().copied().unwrap_or(0);
+    for (i, count) in stats.guess_distribution.iter().enumerate() {
+        let bar_length = (count * 30).checked_div(most_common).unwrap_or(0);
+        println!("{} | {} {}", i + 1, "#".repeat(bar_length), count);
+    }
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use crate::dictionary::{PICKABLE_WORDS, VALID_WORDS};
+use crate::language::Language;
+use crate::letter_frequency::LetterFrequencies;
+use crate::word_list::{diff, matches_pattern, normalize_lines, parse_lines, validate};
+use std::error::Error;
+use std::fs;
+use std::io;
+
+/// `@answers` and `@guesses` name the built-in lists.
+pub fn read_words(source: &str) -> io::Result<Vec<(usize, String)>> {
+    let builtin: Vec<&str> = match source {
+        "@answers" => PICKABLE_WORDS.words().to_vec(),
+        "@guesses" => [PICKABLE_WORDS.words(), VALID_WORDS.words()].concat(),
+        path => return Ok(parse_lines(&fs::read_to_string(path)?)),
+    };
+    Ok(builtin
+        .into_iter()
+        .enumerate()
+        .map(|(i, word)| (i + 1, word.to_string()))
+        .collect())
+}
+
+fn words_only(lines: Vec<(usize, String)>) -> Vec<String> {
+    lines.into_iter().map(|(_, word)| word).collect()
+}
+
+pub fn run_diff(old: &str, new: &str) -> Result<(), Box<dyn Error>> {
+    let changes = diff(&words_only(read_words(old)?), &words_only(read_words(new)?));
+    for word in &changes.removed {
+        println!("- {}", word);
+    }
+    for word in &changes.added {
+        println!("+ {}", word);
+    }
+    println!(
+        "{} added, {} removed",
+        changes.added.len(),
+        changes.removed.len()
+    );
+    Ok(())
+}
+
+pub fn run_check(
+    language: &Language,
+    answers: &str,
+    guesses: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let answers = normalize_lines(language, read_words(answers)?);
+    let guesses = guesses
+        .map(|guesses| Ok::<_, io::Error>(normalize_lines(language, read_words(guesses)?)))
+        .transpose()?;
+    let issues = validate(language, &answers, guesses.as_deref());
+    if issues.is_empty() {
+        println!("{} answers ok", answers.len());
+        return Ok(());
+    }
+    for issue in &issues {
+        println!("{}", issue);
+    }
+    Err(format!("{} problems found", issues.len()).into())
+}
+
+pub fn run_stats(source: &str) -> Result<(), Box<dyn Error>> {
+    let words = words_only(read_words(source)?);
+    print_letter_frequencies(&LetterFrequencies::from_words(
+        words.iter().map(String::as_str),
+    ));
+    Ok(())
+}
+
+pub fn print_letter_frequencies(frequencies: &LetterFrequencies) {
+    print!("{} words\nletter  words", frequencies.word_count());
+    for position in 1..=frequencies.word_length() {
+        print!(" {:>5}", position);
+    }
+    println!();
+    for letter in frequencies.letters() {
+        print!("{:>6}  {:>5}", letter, frequencies.overall(letter));
+        for position in 0..frequencies.word_length() {
+            print!(" {:>5}", frequencies.at(position, letter));
+        }
+        println!();
+    }
+}
+
+pub fn run_filter(
+    source: &str,
+    pattern: Option<&str>,
+    contains: &str,
+    excludes: &str,
+) -> Result<(), Box<dyn Error>> {
+    let words: Vec<String> = words_only(read_words(source)?)
+        .into_iter()
+        .filter(|word| pattern.is_none_or(|pattern| matches_pattern(word, pattern)))
+        .filter(|word| contains.chars().all(|c| word.contains(c)))
+        .filter(|word| !excludes.chars().any(|c| word.contains(c)))
+        .collect();
+    for word in &words {
+        println!("{}", word);
+    }
+    println!("{} words", words.len());
+    Ok(())
+}
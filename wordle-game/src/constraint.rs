@@ -1,4 +1,5 @@
 use crate::{
+    share::Feedback,
     types::{Guess, LetterState::*, WordleGameState},
     util,
 };
@@ -63,6 +64,118 @@ pub fn word_satisfies_contraint(word: &str, guess_result: &Guess) -> bool {
     true
 }
 
+/// Hard mode: every green letter stays in place and every revealed letter is
+/// reused, as many times as it was revealed.
+pub fn is_hard_mode_legal(word: &str, guesses: &[Guess]) -> bool {
+    let word_letter_counts = util::unique_element_counts(word.chars());
+    guesses.iter().all(|guess_result| {
+        let revealed_counts = util::unique_element_counts(
+            guess_result
+                .iter()
+                .filter(|(_, res)| *res != Incorrect)
+                .map(|(c, _)| *c),
+        );
+        let keeps_placements = word
+            .chars()
+            .zip(guess_result)
+            .all(|(word_c, (guess_c, res))| *res != CorrectPlacement || word_c == *guess_c);
+        keeps_placements
+            && revealed_counts
+                .iter()
+                .all(|(c, count)| word_letter_counts.get(c).unwrap_or(&0) >= count)
+    })
+}
+
+pub fn check_guess(guess: &str, secret_word: &str) -> Guess {
+    let mut guess_result: Guess = guess
+        .chars()
+        .zip(secret_word.chars())
+        .map(|(guess_c, secret_c)| {
+            if guess_c == secret_c {
+                (guess_c, CorrectPlacement)
+            } else {
+                (guess_c, Incorrect)
+            }
+        })
+        .collect();
+
+    // secret letters not already claimed by a correct placement
+    let mut unclaimed: Vec<char> = secret_word
+        .chars()
+        .zip(&guess_result)
+        .filter(|(_, (_, res))| *res != CorrectPlacement)
+        .map(|(secret_c, _)| secret_c)
+        .collect();
+    for (guess_c, res) in guess_result.iter_mut() {
+        if *res == CorrectPlacement {
+            continue;
+        }
+        if let Some(i) = unclaimed.iter().position(|c| c == guess_c) {
+            unclaimed.swap_remove(i);
+            *res = CorrectLetter;
+        }
+    }
+    guess_result
+}
+
+pub fn feedback(guess: &str, secret_word: &str) -> Feedback {
+    check_guess(guess, secret_word)
+        .into_iter()
+        .map(|(_, state)| state)
+        .collect()
+}
+
+/// A secret is consistent with a grid if every row can be produced by
+/// guessing a different word from `guess_words` against it.
+pub fn secret_matches_grid(secret_word: &str, grid: &[Feedback], guess_words: &[&str]) -> bool {
+    let mut unmatched_rows: Vec<&Feedback> = grid.iter().collect();
+    if unmatched_rows
+        .iter()
+        .any(|row| row.len() != secret_word.chars().count())
+    {
+        return false;
+    }
+    for guess in guess_words {
+        // cheap check on correct placements before computing full feedback
+        let exact: Vec<bool> = guess
+            .chars()
+            .zip(secret_word.chars())
+            .map(|(guess_c, secret_c)| guess_c == secret_c)
+            .collect();
+        let placements_agree = |row: &&Feedback| {
+            row.iter()
+                .zip(&exact)
+                .all(|(res, is_exact)| (*res == CorrectPlacement) == *is_exact)
+        };
+        if !unmatched_rows.iter().any(placements_agree) {
+            continue;
+        }
+        let guess_feedback = feedback(guess, secret_word);
+        if let Some(i) = unmatched_rows
+            .iter()
+            .position(|row| **row == guess_feedback)
+        {
+            unmatched_rows.swap_remove(i);
+        }
+        if unmatched_rows.is_empty() {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn secrets_matching_grid<'a>(
+    grid: &[Feedback],
+    secret_words: &[&'a str],
+    guess_words: &[&str],
+) -> Vec<&'a str> {
+    secret_words
+        .iter()
+        .filter(|secret| secret_matches_grid(secret, grid, guess_words))
+        .copied()
+        .collect()
+}
+
 #[cfg(test)]
 mod test_word_satisfies_contraint {
     use super::word_satisfies_contraint;
@@ -128,3 +241,36 @@ mod test_word_satisfies_contraint {
         assert_eq!(res, false);
     }
 }
+
+#[cfg(test)]
+mod test_secrets_matching_grid {
+    use super::secrets_matching_grid;
+    use crate::share::parse_emoji_grid;
+
+    #[test]
+    fn keeps_only_secrets_that_can_produce_every_row() {
+        let grid = parse_emoji_grid("🟩🟨🟨⬛⬛\n🟩🟩🟩🟩🟩").unwrap();
+        let secrets = ["salad", "crate", "stale"];
+        let guesses = ["slate", "salad", "crate", "stale"];
+        assert_eq!(
+            secrets_matching_grid(&grid, &secrets, &guesses),
+            vec!["salad", "stale"]
+        );
+    }
+
+    #[test]
+    fn needs_a_different_guess_for_each_row() {
+        let grid = parse_emoji_grid("⬛⬛⬛⬛⬛\n⬛⬛⬛⬛⬛\n🟩🟩🟩🟩🟩").unwrap();
+        assert!(secrets_matching_grid(&grid, &["crate"], &["jumpy", "crate"]).is_empty());
+        assert_eq!(
+            secrets_matching_grid(&grid, &["crate"], &["jumpy", "fluid", "crate"]),
+            vec!["crate"]
+        );
+    }
+
+    #[test]
+    fn rejects_rows_of_the_wrong_length() {
+        let grid = parse_emoji_grid("🟩🟩🟩🟩").unwrap();
+        assert!(secrets_matching_grid(&grid, &["salad"], &["salad"]).is_empty());
+    }
+}
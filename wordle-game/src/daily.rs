@@ -0,0 +1,32 @@
+use crate::dictionary::PICKABLE_WORDS;
+
+/// Days since the unix epoch of the first daily puzzle (2021-06-19).
+pub const FIRST_PUZZLE_DAY: u64 = 18_797;
+
+// co-prime with the answer list length so consecutive days don't walk the
+// alphabetically sorted list in order
+const STRIDE: u64 = 7_919;
+
+pub fn puzzle_number(days_since_epoch: u64) -> u64 {
+    days_since_epoch.saturating_sub(FIRST_PUZZLE_DAY)
+}
+
+pub fn daily_secret_word(puzzle_number: u64) -> &'static str {
+    let index = (puzzle_number * STRIDE) % PICKABLE_WORDS.len() as u64;
+    PICKABLE_WORDS.words()[index as usize]
+}
+
+#[cfg(test)]
+mod test_daily_secret_word {
+    use super::daily_secret_word;
+    use crate::dictionary::PICKABLE_WORDS;
+    use std::collections::HashSet;
+
+    #[test]
+    fn does_not_repeat_until_every_answer_was_used() {
+        let words: HashSet<&str> = (0..PICKABLE_WORDS.len() as u64)
+            .map(daily_secret_word)
+            .collect();
+        assert_eq!(words.len(), PICKABLE_WORDS.len());
+    }
+}
@@ -0,0 +1,172 @@
+use rand::{seq::SliceRandom, Rng};
+use std::{collections::HashMap, error::Error, fmt, fs, io, path::Path};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordFrequencies {
+    weights: HashMap<String, f64>,
+    default_weight: f64,
+}
+
+#[derive(Debug)]
+pub enum FrequencyError {
+    Io(io::Error),
+    Malformed { line: usize },
+}
+
+impl fmt::Display for FrequencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrequencyError::Io(err) => write!(f, "unable to read frequencies: {}", err),
+            FrequencyError::Malformed { line } => {
+                write!(f, "malformed frequency entry on line {}", line)
+            }
+        }
+    }
+}
+
+impl Error for FrequencyError {}
+
+impl From<io::Error> for FrequencyError {
+    fn from(err: io::Error) -> Self {
+        FrequencyError::Io(err)
+    }
+}
+
+impl WordFrequencies {
+    pub fn uniform() -> Self {
+        Self {
+            weights: HashMap::new(),
+            default_weight: 1.0,
+        }
+    }
+
+    /// Parses `word count` lines (space, tab or comma separated), as found
+    /// in unigram corpus dumps. Counts are log-scaled so a handful of very
+    /// common words don't drown out the rest. Words missing from the table,
+    /// or counted 0, get the smallest weight seen.
+    pub fn parse(text: &str) -> Result<Self, FrequencyError> {
+        let mut weights = HashMap::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|field| !field.is_empty())
+                .collect();
+            let (word, count) = match fields[..] {
+                [word, count] => (word, count),
+                _ => return Err(FrequencyError::Malformed { line: i + 1 }),
+            };
+            let count = match count.parse::<f64>() {
+                Ok(count) if count.is_finite() && count >= 0.0 => count,
+                _ => return Err(FrequencyError::Malformed { line: i + 1 }),
+            };
+            if count > 0.0 {
+                weights.insert(word.to_lowercase(), (1.0 + count).ln());
+            }
+        }
+        let default_weight = weights.values().copied().fold(f64::INFINITY, f64::min);
+        Ok(Self {
+            weights,
+            default_weight: if default_weight.is_finite() {
+                default_weight
+            } else {
+                1.0
+            },
+        })
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, FrequencyError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn weight(&self, word: &str) -> f64 {
+        self.weights
+            .get(word)
+            .copied()
+            .unwrap_or(self.default_weight)
+    }
+
+    pub fn prior(&self, candidates: &[&str]) -> Vec<f64> {
+        let weights: Vec<f64> = candidates.iter().map(|word| self.weight(word)).collect();
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return vec![1.0 / candidates.len() as f64; candidates.len()];
+        }
+        weights.into_iter().map(|weight| weight / total).collect()
+    }
+
+    pub fn choose<'a, S: AsRef<str>>(&self, words: &'a [S], rng: &mut impl Rng) -> Option<&'a S> {
+        // fails if every weight is zero
+        words
+            .choose_weighted(&mut *rng, |word| self.weight(word.as_ref()))
+            .ok()
+            .or_else(|| words.choose(rng))
+    }
+}
+
+#[cfg(test)]
+mod test_word_frequencies {
+    use super::{FrequencyError, WordFrequencies};
+
+    #[test]
+    fn prior_is_normalized() {
+        let frequencies = WordFrequencies::parse("crate 99\nslate,9\n").unwrap();
+        let prior = frequencies.prior(&["crate", "slate"]);
+        assert!((prior.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(prior[0] > prior[1]);
+        assert_eq!(
+            WordFrequencies::uniform().prior(&["a", "b"]),
+            vec![0.5, 0.5]
+        );
+    }
+
+    #[test]
+    fn missing_words_get_the_smallest_weight() {
+        let frequencies = WordFrequencies::parse("# counts\ncrate\t99\nslate 9").unwrap();
+        assert_eq!(frequencies.weight("would"), frequencies.weight("slate"));
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        for text in [
+            "crate",
+            "crate many",
+            "crate 1 2",
+            "crate -1",
+            "crate inf",
+            "crate NaN",
+        ] {
+            assert!(matches!(
+                WordFrequencies::parse(text),
+                Err(FrequencyError::Malformed { line: 1 })
+            ));
+        }
+    }
+
+    #[test]
+    fn zero_counts_get_the_smallest_weight() {
+        let frequencies = WordFrequencies::parse("crate 99\nslate 9\nwould 0").unwrap();
+        assert_eq!(frequencies.weight("would"), frequencies.weight("slate"));
+
+        let frequencies = WordFrequencies::parse("crate 0\nslate 0").unwrap();
+        assert_eq!(frequencies.prior(&["crate", "slate"]), vec![0.5, 0.5]);
+        assert!(frequencies
+            .choose(&["crate", "slate"], &mut rand::thread_rng())
+            .is_some());
+    }
+
+    #[test]
+    fn chooses_uniformly_when_every_weight_is_zero() {
+        let frequencies = WordFrequencies {
+            weights: [("crate".to_string(), 0.0)].into_iter().collect(),
+            default_weight: 0.0,
+        };
+        assert_eq!(
+            frequencies.choose(&["crate"], &mut rand::thread_rng()),
+            Some(&"crate")
+        );
+    }
+}
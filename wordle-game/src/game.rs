@@ -1,42 +1,146 @@
+use crate::constraint;
 use crate::dictionary::VALID_WORDS;
-use crate::types::{GameCondition, Guess, Guesses, LetterState, WordleGameState};
+use crate::frequency::WordFrequencies;
+use crate::language::{Language, ENGLISH};
+use crate::save::{SaveError, SavedGame, SAVE_FORMAT_VERSION};
+use crate::types::{GameCondition, GameRules, Guess, Guesses, LetterState, WordleGameState};
+use crate::word_list::WordList;
 use rand::seq::SliceRandom;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{
     collections::{HashMap, HashSet},
     iter::FromIterator,
 };
 
+pub const DEFAULT_MAX_GUESSES: usize = 6;
+
+/// With serde, a game is stored as its [`SavedGame`] and the guesses are
+/// replayed against the English word list when it's read back.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "SavedGame", try_from = "SavedGame")
+)]
 pub struct WordleGame {
     dictionary: HashSet<String>,
     guesses: Guesses,
     secret_word: String,
-    max_guesses: usize,
+    rules: GameRules,
     valid_guess_words: HashSet<String>,
+    practice: bool,
+    undone_guesses: Guesses,
+    language: &'static Language,
 }
 
 impl WordleGame {
     pub fn new(dictionary: &[&str], secret_word: &str) -> Self {
+        Self::with_valid_guesses(dictionary, VALID_WORDS.words(), secret_word)
+    }
+
+    pub fn new_with_random_secret_word(dictionary: &[&str]) -> Self {
+        let secret_word = dictionary.choose(&mut rand::thread_rng()).unwrap();
+        Self::new(dictionary, *secret_word)
+    }
+
+    pub fn from_word_list(word_list: &WordList, secret_word: &str) -> Self {
+        let mut game = Self::with_valid_guesses(
+            &word_list.answer_refs(),
+            &word_list.allowed_refs(),
+            secret_word,
+        );
+        game.language = word_list.language();
+        game
+    }
+
+    pub fn from_word_list_with_random_secret_word(word_list: &WordList) -> Self {
+        let secret_word = word_list.answers().choose(&mut rand::thread_rng()).unwrap();
+        Self::from_word_list(word_list, secret_word)
+    }
+
+    pub fn from_word_list_with_weighted_secret_word(
+        word_list: &WordList,
+        frequencies: &WordFrequencies,
+    ) -> Self {
+        match frequencies.choose(word_list.answers(), &mut rand::thread_rng()) {
+            Some(secret_word) => Self::from_word_list(word_list, secret_word),
+            None => Self::from_word_list_with_random_secret_word(word_list),
+        }
+    }
+
+    fn with_valid_guesses(dictionary: &[&str], valid_guesses: &[&str], secret_word: &str) -> Self {
         let dictionary_set = HashSet::from_iter(dictionary.iter().map(|s| s.to_string()));
         let secret_word = secret_word.to_owned();
         assert!(dictionary_set.contains(&secret_word));
-        let valid_guess_words = HashSet::from_iter(VALID_WORDS.iter().map(|s| s.to_string()));
+        let valid_guess_words = HashSet::from_iter(valid_guesses.iter().map(|s| s.to_string()));
 
         Self {
             dictionary: dictionary_set,
             guesses: vec![],
             secret_word,
-            max_guesses: 6,
+            rules: GameRules::default(),
             valid_guess_words,
+            practice: false,
+            undone_guesses: vec![],
+            language: &ENGLISH,
         }
     }
 
-    pub fn new_with_random_secret_word(dictionary: &[&str]) -> Self {
-        let secret_word = dictionary.choose(&mut rand::thread_rng()).unwrap();
-        Self::new(dictionary, *secret_word)
+    pub fn restore(dictionary: &[&str], saved: &SavedGame) -> Result<Self, SaveError> {
+        Self::replay(dictionary, VALID_WORDS.words(), &ENGLISH, saved)
+    }
+
+    pub fn restore_from_word_list(
+        word_list: &WordList,
+        saved: &SavedGame,
+    ) -> Result<Self, SaveError> {
+        Self::replay(
+            &word_list.answer_refs(),
+            &word_list.allowed_refs(),
+            word_list.language(),
+            saved,
+        )
+    }
+
+    fn replay(
+        dictionary: &[&str],
+        valid_guesses: &[&str],
+        language: &'static Language,
+        saved: &SavedGame,
+    ) -> Result<Self, SaveError> {
+        if saved.version != SAVE_FORMAT_VERSION {
+            return Err(SaveError::UnsupportedVersion(saved.version));
+        }
+        if !dictionary.contains(&saved.secret_word.as_str()) {
+            return Err(SaveError::UnknownSecretWord(saved.secret_word.clone()));
+        }
+        let mut game = Self::with_valid_guesses(dictionary, valid_guesses, &saved.secret_word);
+        game.set_max_guesses(saved.max_guesses)
+            .and(game.set_hard_mode(saved.hard_mode))
+            .map_err(|_| SaveError::Malformed)?;
+        game.practice = saved.practice;
+        game.language = language;
+        for guess in &saved.guesses {
+            game.make_guess(guess)
+                .map_err(|reason| SaveError::InvalidGuess(guess.clone(), reason))?;
+        }
+        Ok(game)
+    }
+
+    pub fn save(&self) -> SavedGame {
+        SavedGame {
+            version: SAVE_FORMAT_VERSION,
+            secret_word: self.secret_word.clone(),
+            max_guesses: self.rules.max_guesses,
+            guesses: self.words_already_guessed(),
+            practice: self.practice,
+            hard_mode: self.rules.hard_mode,
+        }
     }
 
     pub fn make_guess(&mut self, guess: &str) -> Result<(), &'static str> {
-        let guess = guess.to_lowercase();
+        let guess = self.language.normalize(guess).ok_or("Invalid word")?;
         if !(self.dictionary.contains(&guess) || self.valid_guess_words.contains(&guess)) {
             return Err("Invalid word");
         }
@@ -46,8 +150,62 @@ impl WordleGame {
         if self.words_already_guessed().contains(&guess) {
             return Err("Already guessed");
         }
+        if self.rules.hard_mode && !constraint::is_hard_mode_legal(&guess, &self.guesses) {
+            return Err("Hard mode: use every revealed hint");
+        }
         let guess_result = Self::check_guess(&guess, &self.secret_word);
         self.guesses.push(guess_result);
+        self.undone_guesses.clear();
+        Ok(())
+    }
+
+    /// Once enabled, practice stays on for the rest of the game.
+    pub fn enable_practice_mode(&mut self) {
+        self.practice = true;
+    }
+
+    pub fn is_practice(&self) -> bool {
+        self.practice
+    }
+
+    pub fn set_hard_mode(&mut self, hard_mode: bool) -> Result<(), &'static str> {
+        if !self.guesses.is_empty() && hard_mode != self.rules.hard_mode {
+            return Err("Hard mode can only be changed before the first guess");
+        }
+        self.rules.hard_mode = hard_mode;
+        Ok(())
+    }
+
+    pub fn set_max_guesses(&mut self, max_guesses: usize) -> Result<(), &'static str> {
+        if !self.guesses.is_empty() && max_guesses != self.rules.max_guesses {
+            return Err("The number of guesses can only be changed before the first guess");
+        }
+        if max_guesses == 0 {
+            return Err("At least one guess is needed");
+        }
+        self.rules.max_guesses = max_guesses;
+        Ok(())
+    }
+
+    pub fn rules(&self) -> GameRules {
+        self.rules
+    }
+
+    pub fn undo(&mut self) -> Result<(), &'static str> {
+        if !self.practice {
+            return Err("Undo is only available in practice mode");
+        }
+        let guess = self.guesses.pop().ok_or("Nothing to undo")?;
+        self.undone_guesses.push(guess);
+        Ok(())
+    }
+
+    pub fn redo(&mut self) -> Result<(), &'static str> {
+        if !self.practice {
+            return Err("Redo is only available in practice mode");
+        }
+        let guess = self.undone_guesses.pop().ok_or("Nothing to redo")?;
+        self.guesses.push(guess);
         Ok(())
     }
 
@@ -55,6 +213,7 @@ impl WordleGame {
         WordleGameState {
             guesses: &self.guesses,
             condition: self.game_condition(),
+            rules: self.rules,
         }
     }
 
@@ -66,7 +225,7 @@ impl WordleGame {
         });
         if has_won {
             GameCondition::Win
-        } else if self.guesses.len() >= self.max_guesses {
+        } else if self.guesses.len() >= self.rules.max_guesses {
             GameCondition::Loss
         } else {
             GameCondition::Playing
@@ -106,30 +265,21 @@ impl WordleGame {
     }
 
     fn check_guess(guess: &str, secret_word: &str) -> Guess {
-        let mut guess_result = Vec::with_capacity(guess.len());
-        let mut letter_counts = crate::util::unique_element_counts(secret_word.chars());
-        let zipped = guess.chars().zip(secret_word.chars());
-        for (guess_c, selected_c) in zipped {
-            if guess_c == selected_c {
-                guess_result.push((guess_c, LetterState::CorrectPlacement));
-                *letter_counts.get_mut(&selected_c).unwrap() -= 1;
-            } else {
-                guess_result.push((guess_c, LetterState::Incorrect))
-            }
-        }
+        constraint::check_guess(guess, secret_word)
+    }
+}
 
-        for (i, guess_c) in guess.chars().enumerate() {
-            if i >= guess_result.len() {
-                println!("{} {:?}", guess, guess_result)
-            }
-            if let Some(count) = letter_counts.get_mut(&guess_c) {
-                if *count > 0 && guess_result[i].1 != LetterState::CorrectPlacement {
-                    guess_result[i] = (guess_c, LetterState::CorrectLetter);
-                    *count -= 1;
-                }
-            }
-        }
-        guess_result
+impl From<WordleGame> for SavedGame {
+    fn from(game: WordleGame) -> Self {
+        game.save()
+    }
+}
+
+impl TryFrom<SavedGame> for WordleGame {
+    type Error = SaveError;
+
+    fn try_from(saved: SavedGame) -> Result<Self, Self::Error> {
+        Self::restore_from_word_list(&ENGLISH.word_list(), &saved)
     }
 }
 
@@ -221,3 +371,170 @@ mod test_get_guess_result {
         assert_eq!(WordleGame::check_guess(guess, secret), expected_result)
     }
 }
+
+#[cfg(test)]
+mod test_save_and_restore {
+    use super::WordleGame;
+    use crate::dictionary::PICKABLE_WORDS;
+    use crate::save::{SaveError, SavedGame};
+
+    #[test]
+    fn restores_guesses_and_secret() {
+        let mut game = WordleGame::new(PICKABLE_WORDS.words(), "crate");
+        game.make_guess("slate").unwrap();
+        game.make_guess("would").unwrap();
+
+        let restored = WordleGame::restore(PICKABLE_WORDS.words(), &game.save()).unwrap();
+        assert_eq!(restored.secret_word(), "crate");
+        assert_eq!(restored.game_state().guesses, game.game_state().guesses);
+        assert_eq!(restored.game_condition(), game.game_condition());
+    }
+
+    #[test]
+    fn rejects_invalid_saved_guesses() {
+        let mut saved = WordleGame::new(PICKABLE_WORDS.words(), "crate").save();
+        saved.guesses.push("zzzzz".to_string());
+        assert_eq!(
+            WordleGame::restore(PICKABLE_WORDS.words(), &saved).err(),
+            Some(SaveError::InvalidGuess("zzzzz".to_string(), "Invalid word"))
+        );
+    }
+
+    #[test]
+    fn converts_through_saved_games() {
+        let mut game = WordleGame::new(PICKABLE_WORDS.words(), "crate");
+        game.make_guess("slate").unwrap();
+        let restored = WordleGame::try_from(SavedGame::from(game.clone())).unwrap();
+        assert_eq!(restored.game_state().guesses, game.game_state().guesses);
+
+        let mut saved = game.save();
+        saved.guesses.push("zzzzz".to_string());
+        assert!(WordleGame::try_from(saved).is_err());
+    }
+
+    #[test]
+    fn rejects_saves_without_guesses_allowed() {
+        let mut saved = WordleGame::new(PICKABLE_WORDS.words(), "crate").save();
+        saved.max_guesses = 0;
+        assert_eq!(
+            WordleGame::restore(PICKABLE_WORDS.words(), &saved).err(),
+            Some(SaveError::Malformed)
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_undo {
+    use super::WordleGame;
+    use crate::dictionary::PICKABLE_WORDS;
+    use crate::types::GameCondition;
+
+    #[test]
+    fn requires_practice_mode() {
+        let mut game = WordleGame::new(PICKABLE_WORDS.words(), "crate");
+        game.make_guess("slate").unwrap();
+        assert!(game.undo().is_err());
+        assert_eq!(game.words_already_guessed(), vec!["slate"]);
+    }
+
+    #[test]
+    fn undoes_and_redoes_guesses() {
+        let mut game = WordleGame::new(PICKABLE_WORDS.words(), "crate");
+        game.enable_practice_mode();
+        game.make_guess("slate").unwrap();
+        game.make_guess("crate").unwrap();
+        assert_eq!(game.game_condition(), GameCondition::Win);
+
+        game.undo().unwrap();
+        assert_eq!(game.game_condition(), GameCondition::Playing);
+        assert_eq!(game.words_already_guessed(), vec!["slate"]);
+
+        game.redo().unwrap();
+        assert_eq!(game.game_condition(), GameCondition::Win);
+        assert_eq!(game.redo(), Err("Nothing to redo"));
+    }
+
+    #[test]
+    fn new_guess_clears_redo() {
+        let mut game = WordleGame::new(PICKABLE_WORDS.words(), "crate");
+        game.enable_practice_mode();
+        game.make_guess("slate").unwrap();
+        game.undo().unwrap();
+        game.make_guess("would").unwrap();
+        assert_eq!(game.redo(), Err("Nothing to redo"));
+        assert_eq!(game.words_already_guessed(), vec!["would"]);
+    }
+}
+
+#[cfg(test)]
+mod test_word_list_game {
+    use super::WordleGame;
+    use crate::language::{ENGLISH, SPANISH};
+    use crate::word_list::WordList;
+
+    #[test]
+    fn only_accepts_guesses_from_the_list() {
+        let word_list = WordList::parse(&ENGLISH, "planet", Some("planet\nstring")).unwrap();
+        let mut game = WordleGame::from_word_list(&word_list, "planet");
+        assert_eq!(game.make_guess("crate"), Err("Invalid word"));
+        assert_eq!(game.make_guess("string"), Ok(()));
+
+        let restored = WordleGame::restore_from_word_list(&word_list, &game.save()).unwrap();
+        assert_eq!(restored.words_already_guessed(), vec!["string"]);
+    }
+
+    #[test]
+    fn normalizes_guesses_for_the_language() {
+        let word_list = SPANISH.word_list();
+        let mut game = WordleGame::from_word_list(&word_list, "sueño");
+        assert_eq!(game.make_guess("ÁRBOL"), Ok(()));
+        assert_eq!(game.make_guess("suen\u{303}o"), Ok(()));
+        assert_eq!(game.game_condition(), crate::types::GameCondition::Win);
+    }
+}
+
+#[cfg(test)]
+mod test_hard_mode {
+    use super::WordleGame;
+    use crate::dictionary::PICKABLE_WORDS;
+    use crate::types::GameCondition;
+
+    #[test]
+    fn requires_revealed_hints() {
+        let mut game = WordleGame::new(PICKABLE_WORDS.words(), "crate");
+        game.set_hard_mode(true).unwrap();
+        game.make_guess("slate").unwrap();
+        let rejected = Err("Hard mode: use every revealed hint");
+        assert_eq!(game.make_guess("mound"), rejected);
+        assert_eq!(game.make_guess("plate"), Ok(()));
+        assert_eq!(game.make_guess("grape"), rejected);
+        assert_eq!(game.make_guess("irate"), Ok(()));
+    }
+
+    #[test]
+    fn is_fixed_after_the_first_guess() {
+        let mut game = WordleGame::new(PICKABLE_WORDS.words(), "crate");
+        game.make_guess("slate").unwrap();
+        assert!(game.set_hard_mode(true).is_err());
+        assert!(!game.rules().hard_mode);
+    }
+
+    #[test]
+    fn limits_guesses_as_set_before_the_first_guess() {
+        let mut game = WordleGame::new(PICKABLE_WORDS.words(), "crate");
+        assert!(game.set_max_guesses(0).is_err());
+        game.set_max_guesses(1).unwrap();
+        game.make_guess("slate").unwrap();
+        assert!(game.set_max_guesses(6).is_err());
+        assert_eq!(game.game_condition(), GameCondition::Loss);
+    }
+
+    #[test]
+    fn survives_save_and_restore() {
+        let mut game = WordleGame::new(PICKABLE_WORDS.words(), "crate");
+        game.set_hard_mode(true).unwrap();
+        game.make_guess("slate").unwrap();
+        let restored = WordleGame::restore(PICKABLE_WORDS.words(), &game.save()).unwrap();
+        assert!(restored.rules().hard_mode);
+    }
+}
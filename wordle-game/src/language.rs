@@ -0,0 +1,181 @@
+use crate::dictionary::{PackedWords, PICKABLE_WORDS, VALID_WORDS};
+use crate::word_list::WordList;
+use std::fmt;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// Accented letters that are not part of `alphabet` are folded to their base
+/// letter, so `á` is typed and compared as `a` in Spanish while `ñ` stays a
+/// letter of its own.
+pub struct Language {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub alphabet: &'static str,
+    pub keyboard: &'static [&'static str],
+    pub answers: Words,
+    pub guesses: Words,
+}
+
+pub enum Words {
+    Packed(&'static PackedWords),
+    List(&'static [&'static str]),
+}
+
+impl Words {
+    pub fn as_slice(&self) -> &'static [&'static str] {
+        match self {
+            Words::Packed(words) => words.words(),
+            Words::List(words) => words,
+        }
+    }
+}
+
+pub static ENGLISH: Language = Language {
+    code: "en",
+    name: "English",
+    alphabet: "abcdefghijklmnopqrstuvwxyz",
+    keyboard: &["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+    answers: Words::Packed(&PICKABLE_WORDS),
+    guesses: Words::Packed(&VALID_WORDS),
+};
+
+pub static SPANISH: Language = Language {
+    code: "es",
+    name: "Español",
+    alphabet: "abcdefghijklmnñopqrstuvwxyz",
+    keyboard: &["qwertyuiop", "asdfghjklñ", "zxcvbnm"],
+    answers: Words::List(&[
+        "perro", "gatos", "mundo", "casas", "libro", "playa", "nubes", "fuego", "campo", "calle",
+        "noche", "salud", "verde", "negro", "papel", "mesas", "silla", "árbol", "lápiz", "mujer",
+        "padre", "madre", "hijos", "amigo", "feliz", "dulce", "leche", "huevo", "carne", "pollo",
+        "queso", "fruta", "limón", "piano", "radio", "nieve", "clima", "barco", "avión", "coche",
+        "motor", "sueño", "niños", "señal", "pañal", "dueño", "bueno", "mucho", "poder", "joven",
+        "tarde", "color", "bravo", "pluma", "reloj",
+    ]),
+    guesses: Words::List(&[]),
+};
+
+pub static GERMAN: Language = Language {
+    code: "de",
+    name: "Deutsch",
+    alphabet: "abcdefghijklmnopqrstuvwxyzäöüß",
+    keyboard: &["qwertzuiopü", "asdfghjklöä", "yxcvbnmß"],
+    answers: Words::List(&[
+        "apfel", "bäume", "küche", "größe", "wagen", "tisch", "stuhl", "blume", "vogel", "nacht",
+        "abend", "feuer", "stadt", "markt", "platz", "musik", "natur", "sonne", "regen", "wolke",
+        "stein", "insel", "hände", "träne", "löwen", "möwen", "hütte", "schön", "leben", "liebe",
+        "hallo", "danke", "katze", "hunde", "vater", "onkel", "tante", "kunst", "woche", "monat",
+        "uhren", "brief", "heute", "immer", "wurst", "grüße", "fähre", "söhne",
+    ]),
+    guesses: Words::List(&[]),
+};
+
+pub static PORTUGUESE: Language = Language {
+    code: "pt",
+    name: "Português",
+    alphabet: "abcdefghijklmnopqrstuvwxyz",
+    keyboard: &["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+    answers: Words::List(&[
+        "mundo", "terra", "livro", "praia", "campo", "noite", "verde", "preto", "papel", "festa",
+        "amigo", "feliz", "leite", "carne", "fruta", "piano", "rádio", "barco", "avião", "motor",
+        "sonho", "vinho", "irmão", "fácil", "chave", "porta", "braço", "peixe", "nuvem", "chuva",
+        "vento", "tempo", "gente", "corpo", "coisa", "lugar", "nível", "órgão", "ração", "lição",
+        "ações",
+    ]),
+    guesses: Words::List(&[]),
+};
+
+// The other packs only have a few sample answers and no guess list, so
+// they stay out until they have real dictionaries.
+pub static LANGUAGES: &[&Language] = &[&ENGLISH];
+
+impl Language {
+    pub fn by_code(code: &str) -> Option<&'static Language> {
+        LANGUAGES
+            .iter()
+            .copied()
+            .find(|language| language.code == code)
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        self.alphabet.contains(c)
+    }
+
+    pub fn normalize_char(&self, c: char) -> Option<char> {
+        let mut lower = c.to_lowercase();
+        let c = match (lower.next(), lower.next()) {
+            (Some(c), None) => c,
+            _ => return None,
+        };
+        if self.contains(c) {
+            return Some(c);
+        }
+        let mut base = c.nfd().filter(|c| !is_combining_mark(*c));
+        match (base.next(), base.next()) {
+            (Some(c), None) if self.contains(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Normalizes typed or pasted input, which may use decomposed accents.
+    /// Returns `None` if a character isn't part of the alphabet.
+    pub fn normalize(&self, word: &str) -> Option<String> {
+        word.trim().nfc().map(|c| self.normalize_char(c)).collect()
+    }
+
+    pub fn word_list(&'static self) -> WordList {
+        WordList::from_language(self)
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+impl Eq for Language {}
+
+impl fmt::Debug for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Language({})", self.code)
+    }
+}
+
+#[cfg(test)]
+mod test_language {
+    use super::{Language, ENGLISH, GERMAN, LANGUAGES, PORTUGUESE, SPANISH};
+
+    #[test]
+    fn folds_accents_outside_the_alphabet() {
+        assert_eq!(SPANISH.normalize("Árbol"), Some("arbol".to_string()));
+        assert_eq!(SPANISH.normalize("sueño"), Some("sueño".to_string()));
+        assert_eq!(PORTUGUESE.normalize("ações"), Some("acoes".to_string()));
+        assert_eq!(GERMAN.normalize("GRÖSSE"), Some("grösse".to_string()));
+        assert_eq!(ENGLISH.normalize("café"), Some("cafe".to_string()));
+    }
+
+    #[test]
+    fn composes_decomposed_input() {
+        assert_eq!(SPANISH.normalize("suen\u{303}o"), Some("sueño".to_string()));
+        assert_eq!(ENGLISH.normalize("suen\u{303}o"), Some("sueno".to_string()));
+    }
+
+    #[test]
+    fn rejects_letters_outside_the_alphabet() {
+        assert_eq!(ENGLISH.normalize("größe"), None);
+        assert_eq!(SPANISH.normalize("crat3"), None);
+    }
+
+    #[test]
+    fn builtin_words_are_valid() {
+        for language in [&ENGLISH, &SPANISH, &GERMAN, &PORTUGUESE] {
+            let word_list = language.word_list();
+            assert_eq!(word_list.word_length(), 5, "{}", language.code);
+            assert_eq!(word_list.answers().len(), language.answers.as_slice().len());
+        }
+        for language in LANGUAGES {
+            assert_eq!(Language::by_code(language.code), Some(*language));
+        }
+        assert_eq!(Language::by_code("es"), None);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::constraint;
+use crate::types::WordleGameState;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LetterFrequencies {
+    word_count: usize,
+    /// Words containing the letter at least once.
+    overall: HashMap<char, usize>,
+    positional: Vec<HashMap<char, usize>>,
+}
+
+impl LetterFrequencies {
+    pub fn from_words<'a>(words: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut frequencies = Self::default();
+        for word in words {
+            frequencies.word_count += 1;
+            let mut seen = vec![];
+            for (i, c) in word.chars().enumerate() {
+                if frequencies.positional.len() <= i {
+                    frequencies.positional.resize_with(i + 1, HashMap::new);
+                }
+                *frequencies.positional[i].entry(c).or_insert(0) += 1;
+                if !seen.contains(&c) {
+                    seen.push(c);
+                    *frequencies.overall.entry(c).or_insert(0) += 1;
+                }
+            }
+        }
+        frequencies
+    }
+
+    pub fn from_candidates(words: &[&str], game_state: &WordleGameState) -> Self {
+        Self::from_words(
+            words
+                .iter()
+                .copied()
+                .filter(|word| constraint::word_matches(word, game_state)),
+        )
+    }
+
+    pub fn word_count(&self) -> usize {
+        self.word_count
+    }
+
+    pub fn word_length(&self) -> usize {
+        self.positional.len()
+    }
+
+    pub fn overall(&self, c: char) -> usize {
+        self.overall.get(&c).copied().unwrap_or(0)
+    }
+
+    pub fn at(&self, position: usize, c: char) -> usize {
+        self.positional
+            .get(position)
+            .and_then(|counts| counts.get(&c))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn overall_share(&self, c: char) -> f64 {
+        self.share(self.overall(c))
+    }
+
+    pub fn share_at(&self, position: usize, c: char) -> f64 {
+        self.share(self.at(position, c))
+    }
+
+    pub fn letters(&self) -> Vec<char> {
+        let mut letters: Vec<char> = self.overall.keys().copied().collect();
+        letters.sort_by(|a, b| self.overall(*b).cmp(&self.overall(*a)).then(a.cmp(b)));
+        letters
+    }
+
+    fn share(&self, count: usize) -> f64 {
+        if self.word_count == 0 {
+            return 0.0;
+        }
+        count as f64 / self.word_count as f64
+    }
+}
+
+#[cfg(test)]
+mod test_letter_frequencies {
+    use super::LetterFrequencies;
+    use crate::types::{GameCondition, GameRules, LetterState::*, WordleGameState};
+
+    #[test]
+    fn counts_letters_overall_and_by_position() {
+        let frequencies = LetterFrequencies::from_words(["crate", "slate", "eerie"]);
+        assert_eq!(frequencies.word_count(), 3);
+        assert_eq!(frequencies.word_length(), 5);
+        assert_eq!(frequencies.overall('e'), 3);
+        assert_eq!(frequencies.at(4, 'e'), 3);
+        assert_eq!(frequencies.at(0, 'e'), 1);
+        assert_eq!(frequencies.share_at(2, 'a'), 2.0 / 3.0);
+        assert_eq!(frequencies.overall('z'), 0);
+        assert_eq!(&frequencies.letters()[..3], ['e', 'a', 'r']);
+    }
+
+    #[test]
+    fn only_counts_remaining_candidates() {
+        let guesses = vec![vec![
+            ('s', Incorrect),
+            ('l', Incorrect),
+            ('a', CorrectPlacement),
+            ('t', CorrectPlacement),
+            ('e', CorrectPlacement),
+        ]];
+        let game_state = WordleGameState {
+            guesses: &guesses,
+            condition: GameCondition::Playing,
+            rules: GameRules::default(),
+        };
+        let frequencies =
+            LetterFrequencies::from_candidates(&["crate", "slate", "grate"], &game_state);
+        assert_eq!(frequencies.word_count(), 2);
+        assert_eq!(frequencies.share_at(0, 'c'), 0.5);
+        assert_eq!(frequencies.overall_share('r'), 1.0);
+    }
+
+    #[test]
+    fn empty_sets_have_no_shares() {
+        let frequencies = LetterFrequencies::from_words([]);
+        assert_eq!(frequencies.overall_share('e'), 0.0);
+        assert_eq!(frequencies.word_length(), 0);
+    }
+}
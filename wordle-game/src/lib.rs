@@ -1,8 +1,20 @@
+pub mod analysis;
+pub mod challenge;
 pub mod commands;
 pub mod constraint;
+pub mod daily;
 pub mod dictionary;
+pub mod frequency;
 pub mod game;
+pub mod language;
+pub mod letter_frequency;
+pub mod openers;
+pub mod save;
+pub mod share;
 pub mod solver;
 pub mod solver_strategy;
+pub mod stats;
+pub mod suggestions;
 pub mod types;
 pub mod util;
+pub mod word_list;
@@ -1,24 +1,399 @@
-use clap::Command;
+use clap::{Arg, ArgMatches, Command};
 use std::error::Error;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
 use wordle_game::commands;
+use wordle_game::commands::openers::OPENER_ORDERS;
+use wordle_game::frequency::{FrequencyError, WordFrequencies};
+use wordle_game::language::{Language, LANGUAGES};
+use wordle_game::solver_strategy::lookahead::LookaheadConfig;
+use wordle_game::solver_strategy::STRATEGY_NAMES;
+use wordle_game::word_list::{WordList, WordListError};
+
+fn word_list_args() -> [Arg<'static>; 3] {
+    [
+        language_arg(),
+        Arg::new("answers")
+            .long("answers")
+            .value_name("FILE")
+            .takes_value(true)
+            .help("newline-delimited answers to use instead of the built-in list"),
+        Arg::new("guesses")
+            .long("guesses")
+            .value_name("FILE")
+            .takes_value(true)
+            .requires("answers")
+            .help("newline-delimited allowed guesses, including the answers"),
+    ]
+}
+
+fn language_arg() -> Arg<'static> {
+    Arg::new("language")
+        .long("language")
+        .value_name("CODE")
+        .takes_value(true)
+        .possible_values(LANGUAGES.iter().map(|language| language.code))
+        .default_value("en")
+        .help("alphabet, accent folding and built-in words to use")
+}
+
+fn language(matches: &ArgMatches) -> &'static Language {
+    Language::by_code(matches.value_of("language").unwrap()).unwrap()
+}
+
+fn word_source_arg(name: &'static str) -> Arg<'static> {
+    Arg::new(name)
+        .value_name("FILE")
+        .help("word list file, or @answers / @guesses for the built-in lists")
+}
+
+fn words_command() -> Command<'static> {
+    Command::new("words")
+        .about("word list maintenance tools")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("diff")
+                .about("show words added and removed between two lists")
+                .arg(word_source_arg("old").required(true))
+                .arg(word_source_arg("new").required(true)),
+        )
+        .subcommand(
+            Command::new("check")
+                .about("report invalid and duplicate words and answers that can't be guessed")
+                .arg(word_source_arg("answers").required(true))
+                .arg(word_source_arg("guesses").long("guesses").takes_value(true))
+                .arg(language_arg()),
+        )
+        .subcommand(
+            Command::new("stats")
+                .about("letter frequency overall and per position")
+                .arg(word_source_arg("words").default_value("@answers")),
+        )
+        .subcommand(
+            Command::new("filter")
+                .about("list words matching a pattern and letter constraints")
+                .arg(word_source_arg("words").default_value("@answers"))
+                .arg(
+                    Arg::new("pattern")
+                        .long("pattern")
+                        .takes_value(true)
+                        .help("known letters by position, ? for any letter, e.g. c?a?e"),
+                )
+                .arg(
+                    Arg::new("contains")
+                        .long("contains")
+                        .takes_value(true)
+                        .default_value("")
+                        .help("letters that must appear"),
+                )
+                .arg(
+                    Arg::new("excludes")
+                        .long("excludes")
+                        .takes_value(true)
+                        .default_value("")
+                        .help("letters that must not appear"),
+                ),
+        )
+}
+
+fn run_words(matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    use commands::words;
+    match matches.subcommand() {
+        Some(("diff", sub_matches)) => words::run_diff(
+            sub_matches.value_of("old").unwrap(),
+            sub_matches.value_of("new").unwrap(),
+        ),
+        Some(("check", sub_matches)) => words::run_check(
+            language(sub_matches),
+            sub_matches.value_of("answers").unwrap(),
+            sub_matches.value_of("guesses"),
+        ),
+        Some(("stats", sub_matches)) => words::run_stats(sub_matches.value_of("words").unwrap()),
+        Some(("filter", sub_matches)) => words::run_filter(
+            sub_matches.value_of("words").unwrap(),
+            sub_matches.value_of("pattern"),
+            sub_matches.value_of("contains").unwrap(),
+            sub_matches.value_of("excludes").unwrap(),
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn frequencies_arg() -> Arg<'static> {
+    Arg::new("frequencies")
+        .long("frequencies")
+        .value_name("FILE")
+        .takes_value(true)
+        .help("`word count` lines used as a prior by --easy and weighted-entropy")
+}
+
+fn frequency_args() -> [Arg<'static>; 2] {
+    [
+        frequencies_arg(),
+        Arg::new("easy")
+            .long("easy")
+            .requires("frequencies")
+            .help("pick common words as secrets more often"),
+    ]
+}
+
+fn frequencies(matches: &ArgMatches) -> Result<Option<WordFrequencies>, FrequencyError> {
+    commands::load_frequencies(matches.value_of("frequencies").map(Path::new))
+}
+
+fn word_list(matches: &ArgMatches) -> Result<WordList, WordListError> {
+    commands::load_word_list(
+        language(matches),
+        matches.value_of("answers").map(Path::new),
+        matches.value_of("guesses").map(Path::new),
+    )
+}
+
+fn review_arg() -> Arg<'static> {
+    Arg::new("review")
+        .long("review")
+        .value_name("STRATEGY")
+        .takes_value(true)
+        .possible_values(STRATEGY_NAMES)
+        .help("review each guess of a finished game against this strategy")
+}
+
+fn lookahead_args() -> [Arg<'static>; 4] {
+    [
+        Arg::new("depth")
+            .long("depth")
+            .takes_value(true)
+            .help("guesses the lookahead strategy searches ahead [default: 2]"),
+        Arg::new("top-k")
+            .long("top-k")
+            .takes_value(true)
+            .help("guesses the lookahead strategy searches at each step [default: 5]"),
+        Arg::new("max-nodes")
+            .long("max-nodes")
+            .takes_value(true)
+            .help("feedback the lookahead strategy may compute per move [default: 5000000]"),
+        Arg::new("time-limit")
+            .long("time-limit")
+            .value_name("MS")
+            .takes_value(true)
+            .help("time the lookahead strategy may spend per move"),
+    ]
+}
+
+fn lookahead(matches: &ArgMatches) -> Result<LookaheadConfig, clap::Error> {
+    let mut config = LookaheadConfig::default();
+    if matches.is_present("depth") {
+        config.depth = matches.value_of_t("depth")?;
+    }
+    if matches.is_present("top-k") {
+        config.top_k = matches.value_of_t("top-k")?;
+    }
+    if matches.is_present("max-nodes") {
+        config.max_nodes = matches.value_of_t("max-nodes")?;
+    }
+    if matches.is_present("time-limit") {
+        config.time_limit = Some(Duration::from_millis(matches.value_of_t("time-limit")?));
+    }
+    Ok(config)
+}
 
 fn cli() -> Command<'static> {
     Command::new("wordle-solver")
         .about("a wordle game an solving strategies")
         .subcommand_required(true)
         .arg_required_else_help(true)
-        .subcommand(Command::new("game").about("play game"))
-        .subcommand(Command::new("solver").about("run solver analysis"))
+        .subcommand(
+            Command::new("game")
+                .about("play game")
+                .arg(
+                    Arg::new("save")
+                        .long("save")
+                        .value_name("FILE")
+                        .takes_value(true)
+                        .help("resume from and save progress to FILE"),
+                )
+                .arg(
+                    Arg::new("practice")
+                        .long("practice")
+                        .help("allow undoing guesses; the game is not recorded in stats"),
+                )
+                .arg(
+                    Arg::new("hard")
+                        .long("hard")
+                        .help("every guess must use the hints revealed so far"),
+                )
+                .arg(review_arg())
+                .args(word_list_args())
+                .args(frequency_args()),
+        )
+        .subcommand(
+            Command::new("solver")
+                .about("run solver analysis")
+                .arg(
+                    Arg::new("strategy")
+                        .long("strategy")
+                        .takes_value(true)
+                        .possible_values(STRATEGY_NAMES)
+                        .default_value("random")
+                        .help("strategy to benchmark"),
+                )
+                .arg(
+                    Arg::new("games")
+                        .long("games")
+                        .takes_value(true)
+                        .default_value("10000")
+                        .help("number of games to play"),
+                )
+                .arg(
+                    Arg::new("mode")
+                        .long("mode")
+                        .takes_value(true)
+                        .possible_values(["normal", "hard", "both"])
+                        .default_value("normal")
+                        .help("benchmark in normal mode, hard mode or both"),
+                )
+                .args(lookahead_args())
+                .args(word_list_args())
+                .args(frequency_args()),
+        )
+        .subcommand(
+            Command::new("openers")
+                .about("rank opening guesses by how well they split the answers")
+                .arg(
+                    Arg::new("opener")
+                        .long("opener")
+                        .value_name("WORD")
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .help("score this opener instead of ranking; repeat to score a sequence"),
+                )
+                .arg(
+                    Arg::new("top")
+                        .long("top")
+                        .takes_value(true)
+                        .default_value("10")
+                        .help("number of openers to show"),
+                )
+                .arg(
+                    Arg::new("by")
+                        .long("by")
+                        .takes_value(true)
+                        .possible_values(OPENER_ORDERS)
+                        .default_value("entropy")
+                        .help(
+                            "rank by bits of information, expected remaining answers or worst case",
+                        ),
+                )
+                .arg(
+                    Arg::new("simulate")
+                        .long("simulate")
+                        .value_name("STRATEGY")
+                        .takes_value(true)
+                        .possible_values(STRATEGY_NAMES)
+                        .help("also show the average guesses over every answer with this strategy"),
+                )
+                .arg(frequencies_arg())
+                .args(word_list_args()),
+        )
+        .subcommand(
+            Command::new("review")
+                .about("review the guesses of a game against a strategy")
+                .arg(
+                    Arg::new("secret")
+                        .required(true)
+                        .help("the answer of the game"),
+                )
+                .arg(
+                    Arg::new("guesses")
+                        .required(true)
+                        .multiple_values(true)
+                        .help("the guesses made, in order"),
+                )
+                .arg(
+                    Arg::new("strategy")
+                        .long("strategy")
+                        .takes_value(true)
+                        .possible_values(STRATEGY_NAMES)
+                        .default_value("entropy")
+                        .help("strategy to compare the guesses with"),
+                )
+                .arg(
+                    Arg::new("hard")
+                        .long("hard")
+                        .help("the game was played in hard mode"),
+                )
+                .arg(frequencies_arg())
+                .args(word_list_args()),
+        )
+        .subcommand(Command::new("stats").about("show statistics for played games"))
+        .subcommand(words_command())
+        .subcommand(
+            Command::new("grid")
+                .about("list answers consistent with a shared result grid")
+                .args(word_list_args()),
+        )
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
+fn run() -> Result<(), Box<dyn Error>> {
     let matches = cli().get_matches();
 
     match matches.subcommand() {
-        Some(("game", _sub_matches)) => commands::game::run_cli_game(),
-        Some(("solver", _sub_matches)) => {
-            commands::solver::run_solver();
-        }
+        Some(("game", sub_matches)) => commands::game::run_cli_game(
+            &word_list(sub_matches)?,
+            sub_matches.value_of("save").map(Path::new),
+            sub_matches.is_present("practice"),
+            sub_matches.is_present("hard"),
+            frequencies(sub_matches)?.as_ref(),
+            sub_matches.is_present("easy"),
+            sub_matches.value_of("review"),
+        )?,
+        Some(("solver", sub_matches)) => commands::solver::run_solver(
+            &word_list(sub_matches)?,
+            frequencies(sub_matches)?.as_ref(),
+            sub_matches.value_of("strategy").unwrap(),
+            sub_matches.value_of_t("games")?,
+            sub_matches.is_present("easy"),
+            match sub_matches.value_of("mode").unwrap() {
+                "hard" => &[true],
+                "both" => &[false, true],
+                _ => &[false],
+            },
+            &lookahead(sub_matches)?,
+        )?,
+        Some(("openers", sub_matches)) => commands::openers::run_openers(
+            &word_list(sub_matches)?,
+            &sub_matches
+                .values_of("opener")
+                .map(|openers| openers.map(String::from).collect::<Vec<_>>())
+                .unwrap_or_default(),
+            sub_matches.value_of_t("top")?,
+            sub_matches.value_of("by").unwrap(),
+            sub_matches.value_of("simulate"),
+            frequencies(sub_matches)?.as_ref(),
+        )?,
+        Some(("review", sub_matches)) => commands::review::run_review(
+            &word_list(sub_matches)?,
+            sub_matches.value_of("secret").unwrap(),
+            &sub_matches
+                .values_of("guesses")
+                .unwrap()
+                .collect::<Vec<_>>(),
+            sub_matches.value_of("strategy").unwrap(),
+            frequencies(sub_matches)?.as_ref(),
+            sub_matches.is_present("hard"),
+        )?,
+        Some(("grid", sub_matches)) => commands::grid::run_grid_analysis(&word_list(sub_matches)?)?,
+        Some(("stats", _sub_matches)) => commands::stats::run_stats()?,
+        Some(("words", sub_matches)) => run_words(sub_matches)?,
         _ => unreachable!(),
     };
     Ok(())
@@ -0,0 +1,91 @@
+//! Scores opening guesses by how well they split the answers.
+use crate::game::WordleGame;
+use crate::solver::WordleSolver;
+use crate::solver_strategy::{entropy::split_answers, SolverStrategy};
+use crate::types::GameCondition;
+use crate::word_list::WordList;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenerScore {
+    pub openers: Vec<String>,
+    pub entropy: f64,
+    pub expected_remaining: f64,
+    pub worst_case: usize,
+}
+
+pub fn score_openers(openers: &[&str], answers: &[&str]) -> OpenerScore {
+    let split = split_answers(openers, answers);
+    OpenerScore {
+        openers: openers.iter().map(|s| s.to_string()).collect(),
+        entropy: split.entropy,
+        expected_remaining: split.expected_remaining,
+        worst_case: split.worst_case,
+    }
+}
+
+pub fn rank_openers(guesses: &[&str], answers: &[&str]) -> Vec<OpenerScore> {
+    let mut scores: Vec<OpenerScore> = guesses
+        .iter()
+        .map(|guess| score_openers(&[guess], answers))
+        .collect();
+    scores.sort_by(|a, b| b.entropy.total_cmp(&a.entropy));
+    scores
+}
+
+/// Lost games count as one more guess than allowed.
+pub fn simulate(word_list: &WordList, openers: &[&str], strategy: &mut dyn SolverStrategy) -> f64 {
+    let mut total_guesses = 0;
+    for secret_word in word_list.answers() {
+        let mut game = WordleGame::from_word_list(word_list, secret_word);
+        for opener in openers {
+            if game.game_condition() == GameCondition::Playing {
+                game.make_guess(opener)
+                    .expect("openers are allowed guesses");
+            }
+        }
+        let score = WordleSolver::new(&mut game, strategy).run_game();
+        total_guesses += match score.result {
+            GameCondition::Win => score.num_guesses,
+            _ => game.rules().max_guesses + 1,
+        };
+    }
+    total_guesses as f64 / word_list.answers().len() as f64
+}
+
+#[cfg(test)]
+mod test_openers {
+    use super::{rank_openers, score_openers, simulate};
+    use crate::language::ENGLISH;
+    use crate::solver_strategy::narrowing_random::NarrowingRandomWordleSolver;
+    use crate::word_list::WordList;
+
+    const ANSWERS: [&str; 4] = ["crate", "grate", "irate", "plate"];
+
+    #[test]
+    fn scores_how_openers_split_the_answers() {
+        let score = score_openers(&["crate"], &ANSWERS);
+        assert_eq!(score.worst_case, 2);
+        assert_eq!(score.expected_remaining, 1.5);
+        assert_eq!(score.entropy, 1.5);
+
+        let pair = score_openers(&["crate", "gipsy"], &ANSWERS);
+        assert_eq!(pair.worst_case, 1);
+        assert_eq!(pair.entropy, 2.0);
+    }
+
+    #[test]
+    fn ranks_the_most_informative_first() {
+        let ranked = rank_openers(&["mound", "crate", "gipsy"], &ANSWERS);
+        let order: Vec<&str> = ranked.iter().map(|s| s.openers[0].as_str()).collect();
+        assert_eq!(order, ["gipsy", "crate", "mound"]);
+    }
+
+    #[test]
+    fn simulates_games_after_the_openers() {
+        let guesses = format!("{}\ngipsy", ANSWERS.join("\n"));
+        let word_list = WordList::parse(&ENGLISH, &ANSWERS.join("\n"), Some(&guesses)).unwrap();
+        let mut strategy = NarrowingRandomWordleSolver::new(&ANSWERS);
+        // "gipsy" tells every answer apart, so the next guess always wins.
+        assert_eq!(simulate(&word_list, &["gipsy"], &mut strategy), 2.0);
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt};
+
+pub const SAVE_FORMAT_VERSION: u32 = 1;
+
+/// The minimal state needed to rebuild a `WordleGame`: guesses are stored
+/// as plain words and re-checked against the secret when restoring.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SavedGame {
+    pub version: u32,
+    pub secret_word: String,
+    pub max_guesses: usize,
+    pub guesses: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub practice: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hard_mode: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SaveError {
+    Malformed,
+    UnsupportedVersion(u32),
+    UnknownSecretWord(String),
+    InvalidGuess(String, &'static str),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Malformed => write!(f, "malformed saved game"),
+            SaveError::UnsupportedVersion(version) => {
+                write!(f, "unsupported save format version {}", version)
+            }
+            SaveError::UnknownSecretWord(word) => {
+                write!(f, "secret word {:?} is not in the dictionary", word)
+            }
+            SaveError::InvalidGuess(word, reason) => {
+                write!(f, "saved guess {:?} is invalid: {}", word, reason)
+            }
+        }
+    }
+}
+
+impl Error for SaveError {}
+
+impl SavedGame {
+    /// Encodes the game as a single line, e.g. `v1;6;crate;slate,would;`.
+    /// The last field lists flags, e.g. `;practice,hard`.
+    pub fn encode(&self) -> String {
+        let flags: Vec<&str> = [(self.practice, "practice"), (self.hard_mode, "hard")]
+            .iter()
+            .filter(|(set, _)| *set)
+            .map(|(_, flag)| *flag)
+            .collect();
+        format!(
+            "v{};{};{};{};{}",
+            self.version,
+            self.max_guesses,
+            self.secret_word,
+            self.guesses.join(","),
+            flags.join(",")
+        )
+    }
+
+    pub fn decode(text: &str) -> Result<Self, SaveError> {
+        let mut fields = text.trim().split(';');
+        let version = fields
+            .next()
+            .and_then(|field| field.strip_prefix('v'))
+            .and_then(|version| version.parse().ok())
+            .ok_or(SaveError::Malformed)?;
+        if version != SAVE_FORMAT_VERSION {
+            return Err(SaveError::UnsupportedVersion(version));
+        }
+        let max_guesses = fields
+            .next()
+            .and_then(|field| field.parse().ok())
+            .filter(|&max_guesses| max_guesses > 0)
+            .ok_or(SaveError::Malformed)?;
+        let secret_word = fields.next().ok_or(SaveError::Malformed)?.to_string();
+        let guesses = match fields.next().ok_or(SaveError::Malformed)? {
+            "" => vec![],
+            guesses => guesses.split(',').map(|s| s.to_string()).collect(),
+        };
+        let flags: Vec<&str> = match fields.next().ok_or(SaveError::Malformed)? {
+            "" => vec![],
+            flags => flags.split(',').collect(),
+        };
+        if !flags.iter().all(|flag| ["practice", "hard"].contains(flag)) {
+            return Err(SaveError::Malformed);
+        }
+        if fields.next().is_some() || secret_word.is_empty() {
+            return Err(SaveError::Malformed);
+        }
+        Ok(Self {
+            version,
+            secret_word,
+            max_guesses,
+            guesses,
+            practice: flags.contains(&"practice"),
+            hard_mode: flags.contains(&"hard"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_saved_game {
+    use super::{SaveError, SavedGame, SAVE_FORMAT_VERSION};
+
+    fn saved_game(guesses: &[&str]) -> SavedGame {
+        SavedGame {
+            version: SAVE_FORMAT_VERSION,
+            secret_word: "crate".to_string(),
+            max_guesses: 6,
+            guesses: guesses.iter().map(|s| s.to_string()).collect(),
+            practice: false,
+            hard_mode: false,
+        }
+    }
+
+    #[test]
+    fn round_trips_through_encoding() {
+        let saved = saved_game(&["slate", "would"]);
+        assert_eq!(saved.encode(), "v1;6;crate;slate,would;");
+        assert_eq!(SavedGame::decode(&saved.encode()), Ok(saved));
+    }
+
+    #[test]
+    fn round_trips_practice_games() {
+        let mut saved = saved_game(&["slate"]);
+        saved.practice = true;
+        assert_eq!(saved.encode(), "v1;6;crate;slate;practice");
+        assert_eq!(SavedGame::decode(&saved.encode()), Ok(saved));
+    }
+
+    #[test]
+    fn round_trips_hard_mode_games() {
+        let mut saved = saved_game(&["slate"]);
+        saved.practice = true;
+        saved.hard_mode = true;
+        assert_eq!(saved.encode(), "v1;6;crate;slate;practice,hard");
+        assert_eq!(SavedGame::decode(&saved.encode()), Ok(saved));
+    }
+
+    #[test]
+    fn round_trips_without_guesses() {
+        let saved = saved_game(&[]);
+        assert_eq!(SavedGame::decode(&saved.encode()), Ok(saved));
+    }
+
+    #[test]
+    fn rejects_unknown_versions() {
+        assert_eq!(
+            SavedGame::decode("v99;6;crate;;"),
+            Err(SaveError::UnsupportedVersion(99))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(SavedGame::decode("crate"), Err(SaveError::Malformed));
+        assert_eq!(
+            SavedGame::decode("v1;six;crate;"),
+            Err(SaveError::Malformed)
+        );
+        assert_eq!(SavedGame::decode("v1;0;crate;;"), Err(SaveError::Malformed));
+        assert_eq!(SavedGame::decode("v1;6;crate"), Err(SaveError::Malformed));
+        assert_eq!(SavedGame::decode("v1;6;crate;"), Err(SaveError::Malformed));
+        assert_eq!(
+            SavedGame::decode("v1;6;crate;;easy"),
+            Err(SaveError::Malformed)
+        );
+    }
+}
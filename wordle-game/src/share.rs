@@ -0,0 +1,291 @@
+use crate::types::{Guess, Guesses, LetterState};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt};
+
+pub type Feedback = Vec<LetterState>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    UnexpectedSymbol { line: usize, symbol: char },
+    RowLengthMismatch { line: usize },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "no emoji rows found"),
+            ParseGridError::UnexpectedSymbol { line, symbol } => {
+                write!(f, "unexpected symbol {:?} on line {}", symbol, line)
+            }
+            ParseGridError::RowLengthMismatch { line } => {
+                write!(f, "row on line {} has a different length", line)
+            }
+        }
+    }
+}
+
+impl Error for ParseGridError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Palette {
+    #[default]
+    Standard,
+    HighContrast,
+    Colorblind,
+}
+
+impl Palette {
+    pub const ALL: [Palette; 3] = [
+        Palette::Standard,
+        Palette::HighContrast,
+        Palette::Colorblind,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Palette::Standard => "standard",
+            Palette::HighContrast => "high-contrast",
+            Palette::Colorblind => "colorblind",
+        }
+    }
+
+    pub fn by_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|palette| palette.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Theme {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 2] = [Theme::Light, Theme::Dark];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+
+    pub fn by_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|theme| theme.name() == name)
+    }
+}
+
+pub fn emoji(state: &LetterState) -> &'static str {
+    themed_emoji(state, Palette::Standard, Theme::Dark)
+}
+
+pub fn themed_emoji(state: &LetterState, palette: Palette, theme: Theme) -> &'static str {
+    match (state, palette) {
+        (LetterState::CorrectPlacement, Palette::Standard) => "🟩",
+        (LetterState::CorrectLetter, Palette::Standard) => "🟨",
+        (LetterState::CorrectPlacement, Palette::HighContrast) => "🟧",
+        (LetterState::CorrectLetter, Palette::HighContrast) => "🟦",
+        (LetterState::CorrectPlacement, Palette::Colorblind) => "🟪",
+        (LetterState::CorrectLetter, Palette::Colorblind) => "🟨",
+        (LetterState::Incorrect, _) => match theme {
+            Theme::Light => "⬜️",
+            Theme::Dark => "⬛️",
+        },
+    }
+}
+
+pub fn emoji_row(guess: &Guess) -> String {
+    themed_emoji_row(guess, Palette::Standard, Theme::Dark)
+}
+
+pub fn emoji_grid(guesses: &Guesses) -> String {
+    themed_emoji_grid(guesses, Palette::Standard, Theme::Dark)
+}
+
+pub fn themed_emoji_row(guess: &Guess, palette: Palette, theme: Theme) -> String {
+    guess
+        .iter()
+        .map(|(_, state)| themed_emoji(state, palette, theme))
+        .collect()
+}
+
+pub fn themed_emoji_grid(guesses: &Guesses, palette: Palette, theme: Theme) -> String {
+    guesses
+        .iter()
+        .map(|guess| themed_emoji_row(guess, palette, theme))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parses a shared result grid back into feedback rows.
+///
+/// Lines without any tiles (e.g. the "Wordle 123 4/6" header) are skipped.
+/// Every palette is accepted, as well as the white and black tiles used by
+/// the light and dark themes.
+pub fn parse_emoji_grid(text: &str) -> Result<Vec<Feedback>, ParseGridError> {
+    let mut rows: Vec<Feedback> = vec![];
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i + 1;
+        if !line.chars().any(|c| tile_state(c).is_some()) {
+            continue;
+        }
+        let mut row = Vec::with_capacity(5);
+        for c in line
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != VARIATION_SELECTOR)
+        {
+            match tile_state(c) {
+                Some(state) => row.push(state),
+                None => {
+                    return Err(ParseGridError::UnexpectedSymbol {
+                        line: line_number,
+                        symbol: c,
+                    })
+                }
+            }
+        }
+        if rows.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(ParseGridError::RowLengthMismatch { line: line_number });
+        }
+        rows.push(row);
+    }
+    if rows.is_empty() {
+        return Err(ParseGridError::Empty);
+    }
+    Ok(rows)
+}
+
+const VARIATION_SELECTOR: char = '\u{fe0f}';
+
+fn tile_state(c: char) -> Option<LetterState> {
+    match c {
+        '🟩' | '🟧' | '🟪' => Some(LetterState::CorrectPlacement),
+        '🟨' | '🟦' => Some(LetterState::CorrectLetter),
+        '⬛' | '⬜' => Some(LetterState::Incorrect),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test_parse_emoji_grid {
+    use super::{
+        emoji_grid, emoji_row, parse_emoji_grid, themed_emoji_grid, themed_emoji_row, Palette,
+        ParseGridError, Theme,
+    };
+    use crate::types::LetterState::*;
+
+    #[test]
+    fn skips_header_lines() {
+        let grid = "Wordle 1,234 2/6\n\n⬛🟨⬛⬛🟩\n🟩🟩🟩🟩🟩";
+        let expected = vec![
+            vec![
+                Incorrect,
+                CorrectLetter,
+                Incorrect,
+                Incorrect,
+                CorrectPlacement,
+            ],
+            vec![CorrectPlacement; 5],
+        ];
+        assert_eq!(parse_emoji_grid(grid), Ok(expected));
+    }
+
+    #[test]
+    fn accepts_high_contrast_and_light_theme() {
+        let grid = "⬜🟦🟧⬜⬜";
+        let expected = vec![vec![
+            Incorrect,
+            CorrectLetter,
+            CorrectPlacement,
+            Incorrect,
+            Incorrect,
+        ]];
+        assert_eq!(parse_emoji_grid(grid), Ok(expected));
+    }
+
+    #[test]
+    fn themes_follow_the_palette() {
+        let guess = vec![
+            ('c', CorrectPlacement),
+            ('r', CorrectLetter),
+            ('a', Incorrect),
+        ];
+        assert_eq!(emoji_row(&guess), "🟩🟨⬛\u{fe0f}");
+        assert_eq!(
+            themed_emoji_row(&guess, Palette::HighContrast, Theme::Light),
+            "🟧🟦⬜\u{fe0f}"
+        );
+        assert_eq!(
+            themed_emoji_row(&guess, Palette::Colorblind, Theme::Dark),
+            "🟪🟨⬛\u{fe0f}"
+        );
+        for palette in Palette::ALL {
+            for theme in Theme::ALL {
+                let grid = themed_emoji_grid(&vec![guess.clone()], palette, theme);
+                assert_eq!(
+                    parse_emoji_grid(&grid),
+                    Ok(vec![vec![CorrectPlacement, CorrectLetter, Incorrect]])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn round_trips_shared_grid() {
+        let guesses = vec![
+            vec![
+                ('s', CorrectPlacement),
+                ('l', CorrectLetter),
+                ('a', CorrectLetter),
+                ('t', Incorrect),
+                ('e', Incorrect),
+            ],
+            vec![
+                ('s', CorrectPlacement),
+                ('a', CorrectPlacement),
+                ('l', CorrectPlacement),
+                ('a', CorrectPlacement),
+                ('d', CorrectPlacement),
+            ],
+        ];
+        let expected: Vec<_> = guesses
+            .iter()
+            .map(|guess| guess.iter().map(|(_, state)| *state).collect::<Vec<_>>())
+            .collect();
+        assert_eq!(parse_emoji_grid(&emoji_grid(&guesses)), Ok(expected));
+    }
+
+    #[test]
+    fn rejects_mismatched_rows() {
+        let grid = "⬛⬛⬛⬛⬛\n⬛⬛⬛⬛";
+        assert_eq!(
+            parse_emoji_grid(grid),
+            Err(ParseGridError::RowLengthMismatch { line: 2 })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_symbols() {
+        let grid = "⬛⬛x⬛⬛";
+        assert_eq!(
+            parse_emoji_grid(grid),
+            Err(ParseGridError::UnexpectedSymbol {
+                line: 1,
+                symbol: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(
+            parse_emoji_grid("Wordle 1,234 X/6"),
+            Err(ParseGridError::Empty)
+        );
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{game::WordleGame, solver_strategy::SolverStrategy, types::GameCondition};
+use crate::{
+    constraint,
+    game::WordleGame,
+    solver_strategy::{entropy::split_answers, SolverStrategy},
+    types::{GameCondition, Guess},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
 pub struct WordleSolver<'a> {
     game: &'a mut WordleGame,
@@ -11,24 +19,142 @@ pub struct WordleGameScore {
     pub result: GameCondition,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SolverStep {
+    pub guess: String,
+    pub result: Guess,
+    pub candidates_before: usize,
+    pub candidates_after: usize,
+    /// Bits the guess was expected to reveal, with every candidate equally
+    /// likely.
+    pub expected_information: f64,
+    /// Candidates left in the largest group the guess could leave together.
+    pub worst_case: usize,
+    pub is_candidate: bool,
+}
+
 impl<'a> WordleSolver<'a> {
     pub fn new(game: &'a mut WordleGame, strategy: &'a mut dyn SolverStrategy) -> Self {
         WordleSolver { game, strategy }
     }
 
-    pub fn run_game(&mut self) -> WordleGameScore {
-        loop {
-            let game_state = self.game.game_state();
-            if game_state.condition != GameCondition::Playing {
-                return WordleGameScore {
-                    num_guesses: game_state.guesses.len(),
-                    result: game_state.condition,
-                };
-            }
-            let guess = self.strategy.next_guess(&game_state);
-            if self.game.make_guess(&guess).is_err() {
-                continue;
+    pub fn step(&mut self) -> Option<String> {
+        while self.game.game_condition() == GameCondition::Playing {
+            let guess = self.strategy.next_guess(&self.game.game_state());
+            if self.game.make_guess(&guess).is_ok() {
+                return Some(guess);
             }
         }
+        None
+    }
+
+    pub fn run_game(&mut self) -> WordleGameScore {
+        while self.step().is_some() {}
+        let game_state = self.game.game_state();
+        WordleGameScore {
+            num_guesses: game_state.guesses.len(),
+            result: game_state.condition,
+        }
+    }
+
+    /// Plays the game out like `run_game`, explaining every guess against
+    /// the `answers` still consistent with the game before it.
+    pub fn run_explained(&mut self, answers: &[&str]) -> Vec<SolverStep> {
+        let mut candidates: Vec<&str> = answers
+            .iter()
+            .copied()
+            .filter(|word| constraint::word_matches(word, &self.game.game_state()))
+            .collect();
+        let mut steps = vec![];
+        while let Some(guess) = self.step() {
+            let score = split_answers(&[&guess], &candidates);
+            let result = self
+                .game
+                .game_state()
+                .guesses
+                .last()
+                .cloned()
+                .unwrap_or_default();
+            let candidates_before = candidates.len();
+            let is_candidate = candidates.contains(&guess.as_str());
+            candidates.retain(|word| constraint::word_satisfies_contraint(word, &result));
+            steps.push(SolverStep {
+                guess,
+                result,
+                candidates_before,
+                candidates_after: candidates.len(),
+                expected_information: score.entropy,
+                worst_case: score.worst_case,
+                is_candidate,
+            });
+        }
+        steps
+    }
+}
+
+impl fmt::Display for SolverStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {} words; expected {:.1} bits, {} at worst",
+            self.guess,
+            self.candidates_before,
+            self.candidates_after,
+            self.expected_information,
+            self.worst_case
+        )?;
+        if self.is_candidate {
+            write!(f, "; could be the answer")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_solver {
+    use super::WordleSolver;
+    use crate::game::WordleGame;
+    use crate::solver_strategy::SolverStrategy;
+    use crate::types::{GameCondition, WordleGameState};
+
+    const ANSWERS: [&str; 4] = ["crate", "grate", "irate", "plate"];
+
+    struct InOrder(usize);
+
+    impl SolverStrategy for InOrder {
+        fn next_guess(&mut self, _game_state: &WordleGameState) -> String {
+            self.0 += 1;
+            ANSWERS[self.0 - 1].to_string()
+        }
+    }
+
+    #[test]
+    fn steps_one_guess_at_a_time() {
+        let mut game = WordleGame::new(&ANSWERS, "grate");
+        let mut strategy = InOrder(0);
+        let mut solver = WordleSolver::new(&mut game, &mut strategy);
+        assert_eq!(solver.step().as_deref(), Some("crate"));
+        assert_eq!(solver.step().as_deref(), Some("grate"));
+        assert_eq!(solver.step(), None);
+        assert_eq!(game.game_condition(), GameCondition::Win);
+    }
+
+    #[test]
+    fn explains_each_guess() {
+        let mut game = WordleGame::new(&ANSWERS, "irate");
+        let mut strategy = InOrder(0);
+        let steps = WordleSolver::new(&mut game, &mut strategy).run_explained(&ANSWERS);
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps[0].candidates_before, 4);
+        assert_eq!(steps[0].candidates_after, 2);
+        assert_eq!(steps[0].worst_case, 2);
+        assert_eq!(steps[0].expected_information, 1.5);
+        assert!(steps[0].is_candidate);
+        assert_eq!(steps[2].candidates_after, 1);
+        assert_eq!(
+            steps[0].to_string(),
+            "crate: 4 -> 2 words; expected 1.5 bits, 2 at worst; could be the answer"
+        );
     }
 }
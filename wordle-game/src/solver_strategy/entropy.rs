@@ -0,0 +1,240 @@
+use super::{history_key, legal_guesses, SolverStrategy};
+use crate::{
+    constraint,
+    frequency::WordFrequencies,
+    types::{LetterState, WordleGameState},
+};
+use std::collections::HashMap;
+
+pub struct EntropyWordleSolver {
+    guess_pool: Vec<String>,
+    answers: Vec<String>,
+    frequencies: WordFrequencies,
+    /// Best guesses by game history. Replaying the same history always gives
+    /// the same guess, so benchmarks only pay for the opener once.
+    cache: HashMap<String, String>,
+}
+
+impl EntropyWordleSolver {
+    pub fn new(guess_pool: &[&str], answers: &[&str], frequencies: WordFrequencies) -> Self {
+        Self {
+            guess_pool: guess_pool.iter().map(|s| s.to_string()).collect(),
+            answers: answers.iter().map(|s| s.to_string()).collect(),
+            frequencies,
+            cache: HashMap::new(),
+        }
+    }
+
+    fn best_guess(&self, game_state: &WordleGameState) -> String {
+        let candidates: Vec<&str> = self
+            .answers
+            .iter()
+            .map(|word| word.as_str())
+            .filter(|word| constraint::word_matches(word, game_state))
+            .collect();
+        let prior = self.frequencies.prior(&candidates);
+        let most_likely = candidates
+            .iter()
+            .zip(&prior)
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(word, _)| *word)
+            .expect("impossible to win if we run out of options");
+        if candidates.len() <= 2 {
+            return most_likely.to_string();
+        }
+        // Guessing a candidate can win outright, which is worth about as much
+        // as a bit of information.
+        let score = |guess: &str| {
+            let win_chance = candidates
+                .iter()
+                .position(|word| *word == guess)
+                .map_or(0.0, |i| prior[i]);
+            expected_information(guess, &candidates, &prior) + win_chance
+        };
+        candidates
+            .iter()
+            .copied()
+            .chain(
+                legal_guesses(&self.guess_pool, game_state)
+                    .filter(|word| !candidates.contains(word)),
+            )
+            .map(|guess| (guess, score(guess)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(guess, _)| guess.to_string())
+            .unwrap_or_else(|| most_likely.to_string())
+    }
+}
+
+impl SolverStrategy for EntropyWordleSolver {
+    fn next_guess(&mut self, game_state: &WordleGameState) -> String {
+        let history = history_key(game_state);
+        if let Some(guess) = self.cache.get(&history) {
+            return guess.clone();
+        }
+        let guess = self.best_guess(game_state);
+        self.cache.insert(history, guess.clone());
+        guess
+    }
+}
+
+pub fn expected_information(guess: &str, candidates: &[&str], prior: &[f64]) -> f64 {
+    let mut outcomes: HashMap<u32, f64> = HashMap::new();
+    for (secret_word, probability) in candidates.iter().zip(prior) {
+        let feedback = constraint::feedback(guess, secret_word);
+        *outcomes.entry(feedback_id(&feedback)).or_insert(0.0) += probability;
+    }
+    outcomes
+        .values()
+        .filter(|p| **p > 0.0)
+        .map(|p| -p * p.log2())
+        .sum()
+}
+
+/// The most information any of `guesses` is expected to reveal, with every
+/// candidate equally likely. Same as the best `expected_information`, but
+/// fast enough to score every allowed guess: letters become byte indices and
+/// feedback is worked out without branching on letters.
+pub fn best_expected_information<'a>(
+    guesses: impl IntoIterator<Item = &'a str>,
+    candidates: &[&str],
+) -> f64 {
+    let word_length = match candidates.first() {
+        Some(word) => word.chars().count(),
+        None => return 0.0,
+    };
+    // letters no candidate has stay 0, so they are never unclaimed
+    let mut letters: HashMap<char, u8> = HashMap::new();
+    for c in candidates.iter().flat_map(|word| word.chars()) {
+        let next = letters.len() + 1;
+        letters.entry(c).or_insert(next as u8);
+    }
+    if letters.len() > u8::MAX as usize {
+        let prior = vec![1.0 / candidates.len() as f64; candidates.len()];
+        return guesses
+            .into_iter()
+            .map(|guess| expected_information(guess, candidates, &prior))
+            .fold(0.0, f64::max);
+    }
+    let to_letters = |word: &str| -> Vec<u8> {
+        word.chars()
+            .map(|c| letters.get(&c).copied().unwrap_or(0))
+            .collect()
+    };
+    let secrets: Vec<u8> = candidates
+        .iter()
+        .flat_map(|word| to_letters(word))
+        .collect();
+    let mut counts = vec![0u32; 3usize.pow(word_length as u32)];
+    let mut touched: Vec<usize> = vec![];
+    let mut best = 0.0f64;
+    for guess in guesses.into_iter().map(to_letters) {
+        if guess.len() != word_length {
+            continue;
+        }
+        for secret in secrets.chunks_exact(word_length) {
+            let mut unclaimed = [0u8; 256];
+            for (s, g) in secret.iter().zip(&guess) {
+                unclaimed[*s as usize] += (s != g) as u8;
+            }
+            let id = guess.iter().zip(secret).fold(0, |id, (g, s)| {
+                let placed = (g == s) as u8;
+                let present = (unclaimed[*g as usize] > 0) as u8 & (1 - placed);
+                unclaimed[*g as usize] -= present;
+                id * 3 + (2 * placed + present) as usize
+            });
+            if counts[id] == 0 {
+                touched.push(id);
+            }
+            counts[id] += 1;
+        }
+        let total = candidates.len() as f64;
+        let information: f64 = touched
+            .drain(..)
+            .map(|id| {
+                let p = std::mem::take(&mut counts[id]) as f64 / total;
+                -p * p.log2()
+            })
+            .sum();
+        best = best.max(information);
+    }
+    best
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnswerSplit {
+    pub entropy: f64,
+    pub expected_remaining: f64,
+    /// Answers remaining in the largest group the guesses leave together.
+    pub worst_case: usize,
+}
+
+/// How `guesses`, played in order, split `answers` by their feedback.
+pub fn split_answers(guesses: &[&str], answers: &[&str]) -> AnswerSplit {
+    let mut groups: HashMap<Vec<u32>, usize> = HashMap::new();
+    for secret_word in answers {
+        let feedback: Vec<u32> = guesses
+            .iter()
+            .map(|guess| feedback_id(&constraint::feedback(guess, secret_word)))
+            .collect();
+        *groups.entry(feedback).or_insert(0) += 1;
+    }
+    let total = answers.len() as f64;
+    AnswerSplit {
+        entropy: groups
+            .values()
+            .map(|size| *size as f64 / total)
+            .map(|p| -p * p.log2())
+            .sum(),
+        expected_remaining: groups
+            .values()
+            .map(|size| (size * size) as f64 / total)
+            .sum(),
+        worst_case: groups.values().copied().max().unwrap_or(0),
+    }
+}
+
+pub(crate) fn feedback_id(feedback: &[LetterState]) -> u32 {
+    feedback.iter().fold(0, |id, state| id * 3 + *state as u32)
+}
+
+#[cfg(test)]
+mod test_entropy {
+    use super::{best_expected_information, expected_information, EntropyWordleSolver};
+    use crate::{frequency::WordFrequencies, game::WordleGame, solver::WordleSolver};
+    use crate::{solver_strategy::SolverStrategy, types::GameCondition};
+
+    #[test]
+    fn splitting_guesses_carry_more_information() {
+        let candidates = ["crate", "grate", "irate", "plate"];
+        let prior = [0.25; 4];
+        let splits_all = expected_information("gipsy", &candidates, &prior);
+        let splits_none = expected_information("mound", &candidates, &prior);
+        assert!((splits_all - 2.0).abs() < 1e-9);
+        assert_eq!(splits_none, 0.0);
+    }
+
+    #[test]
+    fn finds_the_best_expected_information() {
+        let candidates = ["speed", "spend", "erase", "geese", "sense"];
+        let prior = [0.2; 5];
+        let guesses = ["eerie", "geese", "mound", "seeds", "toolong"];
+        let best = guesses
+            .iter()
+            .filter(|guess| guess.len() == 5)
+            .map(|guess| expected_information(guess, &candidates, &prior))
+            .fold(0.0, f64::max);
+        assert!(best > 0.0);
+        assert!((best_expected_information(guesses, &candidates) - best).abs() < 1e-9);
+    }
+
+    #[test]
+    fn prefers_the_most_common_of_two_candidates() {
+        let frequencies = WordFrequencies::parse("crate 10\ngrate 1000").unwrap();
+        let mut strategy = EntropyWordleSolver::new(&[], &["crate", "grate"], frequencies);
+        let mut game = WordleGame::new(&["crate", "grate"], "grate");
+        assert_eq!(strategy.next_guess(&game.game_state()), "grate");
+        let score = WordleSolver::new(&mut game, &mut strategy).run_game();
+        assert_eq!(score.result, GameCondition::Win);
+        assert_eq!(score.num_guesses, 1);
+    }
+}
@@ -0,0 +1,123 @@
+use super::{legal_guesses, SolverStrategy};
+use crate::{constraint, letter_frequency::LetterFrequencies, types::WordleGameState};
+
+pub struct LetterFrequencyWordleSolver {
+    guess_pool: Vec<String>,
+    answers: Vec<String>,
+}
+
+impl LetterFrequencyWordleSolver {
+    pub fn new(guess_pool: &[&str], answers: &[&str]) -> Self {
+        Self {
+            guess_pool: guess_pool.iter().map(|s| s.to_string()).collect(),
+            answers: answers.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl SolverStrategy for LetterFrequencyWordleSolver {
+    fn next_guess(&mut self, game_state: &WordleGameState) -> String {
+        let candidates: Vec<&str> = self
+            .answers
+            .iter()
+            .map(|word| word.as_str())
+            .filter(|word| constraint::word_matches(word, game_state))
+            .collect();
+        let first_candidate = candidates
+            .first()
+            .expect("impossible to win if we run out of options");
+        if candidates.len() <= 2 {
+            return first_candidate.to_string();
+        }
+        let frequencies = LetterFrequencies::from_words(candidates.iter().copied());
+        let tested: Vec<char> = game_state
+            .guesses
+            .iter()
+            .flat_map(|guess| guess.iter().map(|(c, _)| *c))
+            .collect();
+        // Guessing a candidate might win outright.
+        let win_chance = 1.0 / candidates.len() as f64;
+        candidates
+            .iter()
+            .map(|word| (*word, score(word, &frequencies, &tested) + win_chance))
+            .chain(
+                legal_guesses(&self.guess_pool, game_state)
+                    .map(|word| (word, score(word, &frequencies, &tested))),
+            )
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(guess, _)| guess.to_string())
+            .unwrap_or_else(|| first_candidate.to_string())
+    }
+}
+
+/// Sums how often each letter of `guess` is in the same spot among the
+/// candidates, plus how often each new letter appears anywhere. Repeated and
+/// already tested letters only count for their position, and letters every
+/// candidate shares tell nothing, so they don't count at all.
+pub fn score(guess: &str, frequencies: &LetterFrequencies, tested: &[char]) -> f64 {
+    let mut seen = vec![];
+    let mut score = 0.0;
+    for (i, c) in guess.chars().enumerate() {
+        let positional = frequencies.share_at(i, c);
+        if positional < 1.0 {
+            score += positional;
+        }
+        if !seen.contains(&c) && !tested.contains(&c) {
+            let overall = frequencies.overall_share(c);
+            score += overall.min(1.0 - overall);
+        }
+        seen.push(c);
+    }
+    score
+}
+
+#[cfg(test)]
+mod test_letter_frequency {
+    use super::{score, LetterFrequencyWordleSolver};
+    use crate::letter_frequency::LetterFrequencies;
+    use crate::solver_strategy::SolverStrategy;
+    use crate::{constraint, game::WordleGame, solver::WordleSolver, types::GameCondition};
+
+    #[test]
+    fn favors_unique_untested_letters() {
+        let frequencies = LetterFrequencies::from_words(["crate", "grate", "irate", "plate"]);
+        let fresh = score("grips", &frequencies, &[]);
+        let tested = score("grips", &frequencies, &['g', 'r', 'i', 'p', 's']);
+        let repeated = score("ggggg", &frequencies, &[]);
+        assert!(fresh > tested);
+        assert!(fresh > repeated);
+        assert_eq!(
+            score("crate", &LetterFrequencies::from_words(["crate"]), &[]),
+            0.0
+        );
+    }
+
+    #[test]
+    fn solves_games() {
+        let answers = ["crate", "grate", "irate", "plate", "slate", "state"];
+        let mut strategy = LetterFrequencyWordleSolver::new(&[], &answers);
+        for secret_word in answers {
+            let mut game = WordleGame::new(&answers, secret_word);
+            let score = WordleSolver::new(&mut game, &mut strategy).run_game();
+            assert_eq!(score.result, GameCondition::Win);
+        }
+    }
+
+    #[test]
+    fn only_makes_legal_guesses_in_hard_mode() {
+        let answers = ["crate", "grate", "irate", "plate", "slate", "state"];
+        let mut strategy = LetterFrequencyWordleSolver::new(&["gipsy"], &answers);
+        let mut game = WordleGame::new(&answers, "crate");
+        game.make_guess("slate").unwrap();
+        assert_eq!(strategy.next_guess(&game.game_state()), "gipsy");
+
+        let mut game = WordleGame::new(&answers, "crate");
+        game.set_hard_mode(true).unwrap();
+        game.make_guess("slate").unwrap();
+        let guess = strategy.next_guess(&game.game_state());
+        assert!(constraint::is_hard_mode_legal(
+            &guess,
+            game.game_state().guesses
+        ));
+    }
+}
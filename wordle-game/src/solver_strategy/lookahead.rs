@@ -0,0 +1,259 @@
+use super::entropy::{expected_information, feedback_id};
+use super::{history_key, legal_guesses, SolverStrategy};
+use crate::constraint;
+use crate::types::{GameCondition, GameRules, Guesses, WordleGameState};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Rough bits of information a guess reveals, used to estimate the guesses
+/// left once the search runs out of depth or budget.
+const ESTIMATED_BITS_PER_GUESS: f64 = 4.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LookaheadConfig {
+    /// Guesses to look ahead, counting the one being chosen.
+    pub depth: usize,
+    pub top_k: usize,
+    /// Work per move, counted as feedback computed for one guess against one
+    /// candidate. Once too little is left to score every allowed guess, only
+    /// candidates are scored.
+    pub max_nodes: usize,
+    /// Wall clock limit per move. Clocks aren't available in the browser, so
+    /// leave this unset there and rely on `max_nodes`.
+    pub time_limit: Option<Duration>,
+}
+
+impl Default for LookaheadConfig {
+    fn default() -> Self {
+        Self {
+            depth: 2,
+            top_k: 5,
+            max_nodes: 5_000_000,
+            time_limit: None,
+        }
+    }
+}
+
+pub struct LookaheadWordleSolver {
+    guess_pool: Vec<String>,
+    answers: Vec<String>,
+    config: LookaheadConfig,
+    cache: HashMap<String, String>,
+}
+
+impl LookaheadWordleSolver {
+    pub fn new(guess_pool: &[&str], answers: &[&str], config: LookaheadConfig) -> Self {
+        Self {
+            guess_pool: guess_pool.iter().map(|s| s.to_string()).collect(),
+            answers: answers.iter().map(|s| s.to_string()).collect(),
+            config,
+            cache: HashMap::new(),
+        }
+    }
+
+    fn best_guess(&self, game_state: &WordleGameState) -> String {
+        let candidates: Vec<&str> = self
+            .answers
+            .iter()
+            .map(|word| word.as_str())
+            .filter(|word| constraint::word_matches(word, game_state))
+            .collect();
+        let first_candidate = candidates
+            .first()
+            .expect("impossible to win if we run out of options");
+        if candidates.len() <= 2 {
+            return first_candidate.to_string();
+        }
+        let mut search = Search {
+            guess_pool: &self.guess_pool,
+            rules: game_state.rules,
+            config: &self.config,
+            nodes: 0,
+            deadline: self.config.time_limit.map(|limit| Instant::now() + limit),
+        };
+        let mut history = game_state.guesses.clone();
+        let shortlist = search.shortlist(&candidates, &history);
+        let mut best = (shortlist[0], f64::INFINITY);
+        if self.config.depth == 0 {
+            return best.0.to_string();
+        }
+        for guess in shortlist {
+            if search.exhausted() {
+                break;
+            }
+            let expected =
+                search.expected_guesses(guess, &candidates, &mut history, self.config.depth);
+            if expected < best.1 {
+                best = (guess, expected);
+            }
+        }
+        best.0.to_string()
+    }
+}
+
+impl SolverStrategy for LookaheadWordleSolver {
+    fn next_guess(&mut self, game_state: &WordleGameState) -> String {
+        let history = history_key(game_state);
+        if let Some(guess) = self.cache.get(&history) {
+            return guess.clone();
+        }
+        let guess = self.best_guess(game_state);
+        self.cache.insert(history, guess.clone());
+        guess
+    }
+}
+
+struct Search<'a> {
+    guess_pool: &'a [String],
+    rules: GameRules,
+    config: &'a LookaheadConfig,
+    nodes: usize,
+    deadline: Option<Instant>,
+}
+
+impl<'a> Search<'a> {
+    fn exhausted(&self) -> bool {
+        self.nodes >= self.config.max_nodes
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// The `top_k` guesses revealing the most information. Candidates come
+    /// first on ties since they might win outright, and are all that is
+    /// scored once the budget runs low.
+    fn shortlist(&mut self, candidates: &[&'a str], history: &Guesses) -> Vec<&'a str> {
+        let game_state = WordleGameState {
+            guesses: history,
+            condition: GameCondition::Playing,
+            rules: self.rules,
+        };
+        let prior = vec![1.0 / candidates.len() as f64; candidates.len()];
+        let pool_fits =
+            self.nodes + self.guess_pool.len() * candidates.len() <= self.config.max_nodes;
+        let others = legal_guesses(self.guess_pool, &game_state)
+            .filter(|word| pool_fits && !candidates.contains(word));
+        let mut scored: Vec<(&str, f64)> = vec![];
+        for guess in candidates.iter().copied().chain(others) {
+            if !scored.is_empty() && self.exhausted() {
+                break;
+            }
+            self.nodes += candidates.len();
+            scored.push((guess, expected_information(guess, candidates, &prior)));
+        }
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored
+            .into_iter()
+            .take(self.config.top_k.max(1))
+            .map(|(guess, _)| guess)
+            .collect()
+    }
+
+    /// Expected guesses to find the secret among `candidates`, counting
+    /// `guess` and searching `depth - 1` more guesses ahead.
+    fn expected_guesses(
+        &mut self,
+        guess: &str,
+        candidates: &[&'a str],
+        history: &mut Guesses,
+        depth: usize,
+    ) -> f64 {
+        self.nodes += candidates.len();
+        let mut buckets: HashMap<u32, Vec<&'a str>> = HashMap::new();
+        for secret_word in candidates {
+            let feedback = constraint::feedback(guess, secret_word);
+            buckets
+                .entry(feedback_id(&feedback))
+                .or_default()
+                .push(secret_word);
+        }
+        let mut total = 0.0;
+        for bucket in buckets.values() {
+            if bucket[..] == [guess] {
+                total += 1.0;
+                continue;
+            }
+            history.push(constraint::check_guess(guess, bucket[0]));
+            total +=
+                bucket.len() as f64 * (1.0 + self.remaining_guesses(bucket, history, depth - 1));
+            history.pop();
+        }
+        total / candidates.len() as f64
+    }
+
+    fn remaining_guesses(
+        &mut self,
+        candidates: &[&'a str],
+        history: &mut Guesses,
+        depth: usize,
+    ) -> f64 {
+        match candidates.len() {
+            1 => 1.0,
+            2 => 1.5,
+            n if depth == 0 || self.exhausted() => estimate(n),
+            _ => self
+                .shortlist(candidates, history)
+                .into_iter()
+                .map(|guess| self.expected_guesses(guess, candidates, history, depth))
+                .fold(f64::INFINITY, f64::min),
+        }
+    }
+}
+
+fn estimate(n: usize) -> f64 {
+    1.0 + (n as f64).log2() / ESTIMATED_BITS_PER_GUESS
+}
+
+#[cfg(test)]
+mod test_lookahead {
+    use super::{LookaheadConfig, LookaheadWordleSolver};
+    use crate::solver_strategy::SolverStrategy;
+    use crate::{game::WordleGame, solver::WordleSolver, types::GameCondition};
+
+    const ANSWERS: [&str; 6] = ["crate", "grate", "irate", "plate", "slate", "state"];
+
+    #[test]
+    fn solves_games() {
+        let mut strategy = LookaheadWordleSolver::new(&[], &ANSWERS, LookaheadConfig::default());
+        for secret_word in ANSWERS {
+            let mut game = WordleGame::new(&ANSWERS, secret_word);
+            let score = WordleSolver::new(&mut game, &mut strategy).run_game();
+            assert_eq!(score.result, GameCondition::Win);
+        }
+    }
+
+    #[test]
+    fn prefers_guesses_that_split_every_candidate() {
+        // Guessing a candidate leaves the rest in one group; "flumb" tells
+        // them all apart.
+        let answers = ["bakes", "cakes", "fakes", "lakes", "makes"];
+        let config = LookaheadConfig::default();
+        let mut strategy = LookaheadWordleSolver::new(&["flumb"], &answers, config);
+        let game = WordleGame::new(&answers, "cakes");
+        assert_eq!(strategy.next_guess(&game.game_state()), "flumb");
+    }
+
+    #[test]
+    fn scores_only_candidates_on_a_small_budget() {
+        let answers = ["bakes", "cakes", "fakes", "lakes", "makes"];
+        let config = LookaheadConfig {
+            max_nodes: 4,
+            ..LookaheadConfig::default()
+        };
+        let mut strategy = LookaheadWordleSolver::new(&["flumb"], &answers, config);
+        let game = WordleGame::new(&answers, "cakes");
+        assert!(answers.contains(&strategy.next_guess(&game.game_state()).as_str()));
+    }
+
+    #[test]
+    fn still_answers_without_budget() {
+        let config = LookaheadConfig {
+            max_nodes: 0,
+            ..LookaheadConfig::default()
+        };
+        let mut strategy = LookaheadWordleSolver::new(&[], &ANSWERS, config);
+        let mut game = WordleGame::new(&ANSWERS, "slate");
+        let score = WordleSolver::new(&mut game, &mut strategy).run_game();
+        assert_eq!(score.result, GameCondition::Win);
+    }
+}
@@ -1,6 +1,84 @@
+use super::constraint;
+use super::frequency::WordFrequencies;
 use super::types::WordleGameState;
+use super::word_list::WordList;
+pub mod entropy;
+pub mod letter_frequency;
+pub mod lookahead;
 pub mod narrowing_random;
 pub mod random;
+/// Strategies read the game's rules from `game_state`; in hard mode every
+/// guess they make has to be legal.
 pub trait SolverStrategy {
     fn next_guess(&mut self, game_state: &WordleGameState) -> String;
 }
+
+/// The words from `guess_pool` allowed under the game's rules. Candidates
+/// are always allowed, so only extra guesses need filtering.
+pub fn legal_guesses<'a: 'b, 'b>(
+    guess_pool: &'a [String],
+    game_state: &'b WordleGameState<'b>,
+) -> impl Iterator<Item = &'a str> + 'b {
+    guess_pool.iter().map(|word| word.as_str()).filter(|word| {
+        !game_state.rules.hard_mode || constraint::is_hard_mode_legal(word, game_state.guesses)
+    })
+}
+
+fn history_key(game_state: &WordleGameState) -> String {
+    game_state
+        .guesses
+        .iter()
+        .flat_map(|guess| guess.iter())
+        .map(|(c, state)| format!("{}{}", c, *state as u8))
+        .chain(game_state.rules.hard_mode.then(|| "!".to_string()))
+        .collect()
+}
+
+pub const WEIGHTED_ENTROPY: &str = "weighted-entropy";
+
+pub const STRATEGY_NAMES: &[&str] = &[
+    "random",
+    "letter-frequency",
+    "entropy",
+    WEIGHTED_ENTROPY,
+    "lookahead",
+];
+
+/// Builds a strategy by name. Entropy only considers answers to stay fast;
+/// lookahead picks from every allowed guess, bounded by its shortlist.
+/// `weighted-entropy` needs `frequencies` as a prior.
+pub fn build_strategy(
+    name: &str,
+    word_list: &WordList,
+    frequencies: Option<&WordFrequencies>,
+    lookahead: &lookahead::LookaheadConfig,
+) -> Result<Box<dyn SolverStrategy>, String> {
+    let answers = word_list.answer_refs();
+    match name {
+        "random" => Ok(Box::new(
+            narrowing_random::NarrowingRandomWordleSolver::new(&answers),
+        )),
+        "letter-frequency" => Ok(Box::new(
+            letter_frequency::LetterFrequencyWordleSolver::new(&word_list.allowed_refs(), &answers),
+        )),
+        "entropy" => Ok(Box::new(entropy::EntropyWordleSolver::new(
+            &answers,
+            &answers,
+            WordFrequencies::uniform(),
+        ))),
+        WEIGHTED_ENTROPY => match frequencies {
+            Some(frequencies) => Ok(Box::new(entropy::EntropyWordleSolver::new(
+                &answers,
+                &answers,
+                frequencies.clone(),
+            ))),
+            None => Err(format!("{} needs a word frequency file", name)),
+        },
+        "lookahead" => Ok(Box::new(lookahead::LookaheadWordleSolver::new(
+            &word_list.allowed_refs(),
+            &answers,
+            *lookahead,
+        ))),
+        _ => Err(format!("unknown strategy {:?}", name)),
+    }
+}
@@ -0,0 +1,315 @@
+use crate::types::GameCondition;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    convert::Infallible,
+    error::Error,
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameRecord {
+    pub secret_word: String,
+    pub num_guesses: usize,
+    pub result: GameCondition,
+    pub puzzle_number: Option<u64>,
+    /// Missing for games recorded before scoring, or that weren't analyzed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scores: Option<GameScores>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameScores {
+    pub skill: f64,
+    pub luck: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Stats {
+    pub games_played: usize,
+    pub wins: usize,
+    pub current_streak: usize,
+    pub max_streak: usize,
+    pub guess_distribution: Vec<usize>,
+    pub scored_games: usize,
+    pub total_skill: f64,
+    pub total_luck: f64,
+}
+
+impl Stats {
+    pub fn from_records(records: &[GameRecord]) -> Self {
+        let mut stats = Self::default();
+        for record in records {
+            stats.games_played += 1;
+            if let Some(scores) = record.scores {
+                stats.scored_games += 1;
+                stats.total_skill += scores.skill;
+                stats.total_luck += scores.luck;
+            }
+            if record.result != GameCondition::Win {
+                stats.current_streak = 0;
+                continue;
+            }
+            stats.wins += 1;
+            stats.current_streak += 1;
+            stats.max_streak = stats.max_streak.max(stats.current_streak);
+            if stats.guess_distribution.len() < record.num_guesses {
+                stats.guess_distribution.resize(record.num_guesses, 0);
+            }
+            if record.num_guesses > 0 {
+                stats.guess_distribution[record.num_guesses - 1] += 1;
+            }
+        }
+        stats
+    }
+
+    pub fn win_percentage(&self) -> f64 {
+        if self.games_played == 0 {
+            return 0.0;
+        }
+        (self.wins as f64 / self.games_played as f64) * 100_f64
+    }
+
+    pub fn average_skill(&self) -> Option<f64> {
+        (self.scored_games > 0).then(|| self.total_skill / self.scored_games as f64)
+    }
+
+    pub fn average_luck(&self) -> Option<f64> {
+        (self.scored_games > 0).then(|| self.total_luck / self.scored_games as f64)
+    }
+}
+
+pub trait StatsStore {
+    type Error;
+
+    fn load(&self) -> Result<Vec<GameRecord>, Self::Error>;
+
+    fn record(&mut self, record: GameRecord) -> Result<(), Self::Error>;
+
+    fn stats(&self) -> Result<Stats, Self::Error> {
+        Ok(Stats::from_records(&self.load()?))
+    }
+}
+
+#[derive(Default)]
+pub struct MemoryStatsStore {
+    records: Vec<GameRecord>,
+}
+
+impl StatsStore for MemoryStatsStore {
+    type Error = Infallible;
+
+    fn load(&self) -> Result<Vec<GameRecord>, Self::Error> {
+        Ok(self.records.clone())
+    }
+
+    fn record(&mut self, record: GameRecord) -> Result<(), Self::Error> {
+        self.records.push(record);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum StatsError {
+    Io(io::Error),
+    Malformed { line: usize },
+}
+
+impl fmt::Display for StatsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatsError::Io(err) => write!(f, "unable to access stats: {}", err),
+            StatsError::Malformed { line } => write!(f, "malformed stats record on line {}", line),
+        }
+    }
+}
+
+impl Error for StatsError {}
+
+impl From<io::Error> for StatsError {
+    fn from(err: io::Error) -> Self {
+        StatsError::Io(err)
+    }
+}
+
+/// Stores one record per line, e.g. `crate,4,win,-` or `slate,6,loss,412`.
+/// Scored games end with skill and luck, e.g. `crate,4,win,-,-0.52,1.10`.
+pub struct FileStatsStore {
+    path: PathBuf,
+}
+
+impl FileStatsStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn encode(record: &GameRecord) -> String {
+        let result = match record.result {
+            GameCondition::Win => "win",
+            GameCondition::Loss => "loss",
+            GameCondition::Playing => "playing",
+        };
+        let puzzle_number = record
+            .puzzle_number
+            .map_or("-".to_string(), |n| n.to_string());
+        let scores = record.scores.map_or(String::new(), |scores| {
+            format!(",{},{}", scores.skill, scores.luck)
+        });
+        format!(
+            "{},{},{},{}{}",
+            record.secret_word, record.num_guesses, result, puzzle_number, scores
+        )
+    }
+
+    fn decode(line: &str) -> Option<GameRecord> {
+        let fields: Vec<&str> = line.split(',').collect();
+        let (secret_word, num_guesses, result, puzzle_number, scores) = match fields[..] {
+            [secret_word, num_guesses, result, puzzle_number] => {
+                (secret_word, num_guesses, result, puzzle_number, None)
+            }
+            [secret_word, num_guesses, result, puzzle_number, skill, luck] => {
+                let scores = GameScores {
+                    skill: skill.parse().ok()?,
+                    luck: luck.parse().ok()?,
+                };
+                (
+                    secret_word,
+                    num_guesses,
+                    result,
+                    puzzle_number,
+                    Some(scores),
+                )
+            }
+            _ => return None,
+        };
+        let result = match result {
+            "win" => GameCondition::Win,
+            "loss" => GameCondition::Loss,
+            "playing" => GameCondition::Playing,
+            _ => return None,
+        };
+        let puzzle_number = match puzzle_number {
+            "-" => None,
+            n => Some(n.parse().ok()?),
+        };
+        Some(GameRecord {
+            secret_word: secret_word.to_string(),
+            num_guesses: num_guesses.parse().ok()?,
+            result,
+            puzzle_number,
+            scores,
+        })
+    }
+}
+
+impl StatsStore for FileStatsStore {
+    type Error = StatsError;
+
+    fn load(&self) -> Result<Vec<GameRecord>, Self::Error> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+        contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| Self::decode(line.trim()).ok_or(StatsError::Malformed { line: i + 1 }))
+            .collect()
+    }
+
+    fn record(&mut self, record: GameRecord) -> Result<(), Self::Error> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", Self::encode(&record))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_stats {
+    use super::{FileStatsStore, GameRecord, GameScores, MemoryStatsStore, Stats, StatsStore};
+    use crate::types::GameCondition::{self, *};
+
+    fn record(result: GameCondition, num_guesses: usize) -> GameRecord {
+        GameRecord {
+            secret_word: "crate".to_string(),
+            num_guesses,
+            result,
+            puzzle_number: None,
+            scores: None,
+        }
+    }
+
+    #[test]
+    fn tracks_streaks_and_distribution() {
+        let mut store = MemoryStatsStore::default();
+        for (result, num_guesses) in [(Win, 3), (Win, 4), (Loss, 6), (Win, 3)] {
+            store.record(record(result, num_guesses)).unwrap();
+        }
+        let stats = store.stats().unwrap();
+        assert_eq!(
+            stats,
+            Stats {
+                games_played: 4,
+                wins: 3,
+                current_streak: 1,
+                max_streak: 2,
+                guess_distribution: vec![0, 0, 2, 1],
+                ..Stats::default()
+            }
+        );
+        assert_eq!(stats.win_percentage(), 75.0);
+    }
+
+    #[test]
+    fn empty_stats_have_no_win_percentage() {
+        assert_eq!(Stats::from_records(&[]).win_percentage(), 0.0);
+        assert_eq!(Stats::from_records(&[]).average_skill(), None);
+    }
+
+    #[test]
+    fn averages_scores_of_scored_games() {
+        let mut scored = record(Win, 3);
+        scored.scores = Some(GameScores {
+            skill: -1.0,
+            luck: 2.0,
+        });
+        let mut other = scored.clone();
+        other.scores = Some(GameScores {
+            skill: 0.0,
+            luck: -1.0,
+        });
+        let stats = Stats::from_records(&[scored, record(Loss, 6), other]);
+        assert_eq!(stats.scored_games, 2);
+        assert_eq!(stats.average_skill(), Some(-0.5));
+        assert_eq!(stats.average_luck(), Some(0.5));
+    }
+
+    #[test]
+    fn file_records_round_trip() {
+        let mut daily = record(Loss, 6);
+        daily.puzzle_number = Some(412);
+        let mut scored = record(Win, 4);
+        scored.scores = Some(GameScores {
+            skill: -0.25,
+            luck: 1.5,
+        });
+        for record in [record(Win, 4), daily, scored] {
+            let line = FileStatsStore::encode(&record);
+            assert_eq!(FileStatsStore::decode(&line), Some(record));
+        }
+        assert_eq!(FileStatsStore::decode("crate,4,win"), None);
+    }
+}
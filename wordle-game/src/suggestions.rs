@@ -0,0 +1,94 @@
+//! Ranks the remaining candidates as next guesses, for hints.
+use crate::letter_frequency::LetterFrequencies;
+use crate::solver_strategy::{entropy::expected_information, letter_frequency};
+use crate::types::Guesses;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SuggestionMethod {
+    LetterFrequency,
+    Entropy,
+}
+
+impl SuggestionMethod {
+    pub const ALL: [SuggestionMethod; 2] =
+        [SuggestionMethod::LetterFrequency, SuggestionMethod::Entropy];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SuggestionMethod::LetterFrequency => "frequency",
+            SuggestionMethod::Entropy => "entropy",
+        }
+    }
+
+    pub fn by_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|method| method.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Suggestion {
+    pub word: String,
+    /// Higher is better; the unit depends on the method.
+    pub score: f64,
+}
+
+pub fn uncertainty(candidates: usize) -> f64 {
+    (candidates.max(1) as f64).log2()
+}
+
+pub fn rank_suggestions(
+    method: SuggestionMethod,
+    candidates: &[&str],
+    guesses: &Guesses,
+) -> Vec<Suggestion> {
+    let score: Box<dyn Fn(&str) -> f64> = match method {
+        SuggestionMethod::LetterFrequency => {
+            let frequencies = LetterFrequencies::from_words(candidates.iter().copied());
+            let tested: Vec<char> = guesses.iter().flatten().map(|(c, _)| *c).collect();
+            Box::new(move |word| letter_frequency::score(word, &frequencies, &tested))
+        }
+        SuggestionMethod::Entropy => {
+            let prior = vec![1.0 / candidates.len() as f64; candidates.len()];
+            Box::new(move |word| expected_information(word, candidates, &prior))
+        }
+    };
+    let mut suggestions: Vec<Suggestion> = candidates
+        .iter()
+        .map(|word| Suggestion {
+            word: word.to_string(),
+            score: score(word),
+        })
+        .collect();
+    suggestions.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.word.cmp(&b.word)));
+    suggestions
+}
+
+#[cfg(test)]
+mod test_suggestions {
+    use super::{rank_suggestions, uncertainty, SuggestionMethod};
+
+    #[test]
+    fn ranks_the_best_splitter_first() {
+        let candidates = ["cater", "crate", "react", "trace", "zzzzz"];
+        for method in SuggestionMethod::ALL {
+            let ranked = rank_suggestions(method, &candidates, &vec![]);
+            assert_eq!(ranked.len(), candidates.len());
+            assert_eq!(ranked.last().unwrap().word, "zzzzz", "{}", method.name());
+        }
+    }
+
+    #[test]
+    fn looks_up_methods_by_name() {
+        assert_eq!(
+            SuggestionMethod::by_name("entropy"),
+            Some(SuggestionMethod::Entropy)
+        );
+        assert_eq!(SuggestionMethod::by_name("magic"), None);
+        assert_eq!(uncertainty(8), 3.0);
+        assert_eq!(uncertainty(0), 0.0);
+    }
+}
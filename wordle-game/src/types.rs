@@ -1,4 +1,9 @@
+use crate::game::DEFAULT_MAX_GUESSES;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LetterState {
     Incorrect,
     CorrectLetter,
@@ -13,9 +18,27 @@ pub type Guesses = Vec<Guess>;
 pub struct WordleGameState<'a> {
     pub guesses: &'a Guesses,
     pub condition: GameCondition,
+    pub rules: GameRules,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameRules {
+    pub max_guesses: usize,
+    pub hard_mode: bool,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self {
+            max_guesses: DEFAULT_MAX_GUESSES,
+            hard_mode: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GameCondition {
     Win,
     Loss,
@@ -0,0 +1,444 @@
+use crate::language::{Language, ENGLISH};
+use std::{collections::HashSet, error::Error, fmt, fs, io, path::Path};
+
+/// Answers are always allowed as guesses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordList {
+    answers: Vec<String>,
+    allowed: Vec<String>,
+    word_length: usize,
+    language: &'static Language,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListKind {
+    Answers,
+    Guesses,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordListIssue {
+    Empty(ListKind),
+    WrongLength {
+        list: ListKind,
+        line: usize,
+        word: String,
+        expected: usize,
+    },
+    InvalidCharacter {
+        list: ListKind,
+        line: usize,
+        word: String,
+    },
+    Duplicate {
+        list: ListKind,
+        line: usize,
+        word: String,
+    },
+    AnswerNotAllowed {
+        line: usize,
+        word: String,
+    },
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct WordListDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum WordListError {
+    Io(io::Error),
+    Invalid(Vec<WordListIssue>),
+}
+
+impl fmt::Display for ListKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListKind::Answers => write!(f, "answers"),
+            ListKind::Guesses => write!(f, "guesses"),
+        }
+    }
+}
+
+impl fmt::Display for WordListIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use WordListIssue::*;
+        match self {
+            Empty(list) => write!(f, "{} list is empty", list),
+            WrongLength {
+                list,
+                line,
+                word,
+                expected,
+            } => write!(
+                f,
+                "{} line {}: {:?} should have {} letters",
+                list, line, word, expected
+            ),
+            InvalidCharacter { list, line, word } => {
+                write!(
+                    f,
+                    "{} line {}: {:?} has invalid characters",
+                    list, line, word
+                )
+            }
+            Duplicate { list, line, word } => {
+                write!(f, "{} line {}: {:?} is a duplicate", list, line, word)
+            }
+            AnswerNotAllowed { line, word } => write!(
+                f,
+                "answers line {}: {:?} is not an allowed guess",
+                line, word
+            ),
+        }
+    }
+}
+
+impl fmt::Display for WordListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordListError::Io(err) => write!(f, "unable to read word list: {}", err),
+            WordListError::Invalid(issues) => {
+                write!(f, "invalid word list")?;
+                for issue in issues {
+                    write!(f, "\n  {}", issue)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for WordListError {}
+
+impl From<io::Error> for WordListError {
+    fn from(err: io::Error) -> Self {
+        WordListError::Io(err)
+    }
+}
+
+impl Default for WordList {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl WordList {
+    pub fn builtin() -> Self {
+        Self::from_language(&ENGLISH)
+    }
+
+    pub fn from_language(language: &'static Language) -> Self {
+        let normalize = |word: &&str| {
+            language
+                .normalize(word)
+                .expect("built-in words only use letters of their alphabet")
+        };
+        let answers: Vec<String> = language.answers.as_slice().iter().map(normalize).collect();
+        Self {
+            word_length: answers[0].chars().count(),
+            allowed: answers
+                .iter()
+                .cloned()
+                .chain(language.guesses.as_slice().iter().map(normalize))
+                .collect(),
+            answers,
+            language,
+        }
+    }
+
+    /// Builds a word list from newline-delimited text. Blank lines and lines
+    /// starting with `#` are ignored, and accents are folded the way
+    /// `language` does. Without a guesses list only the answers can be
+    /// guessed.
+    pub fn parse(
+        language: &'static Language,
+        answers: &str,
+        guesses: Option<&str>,
+    ) -> Result<Self, WordListError> {
+        let answers = normalize_lines(language, parse_lines(answers));
+        let guesses = guesses.map(|guesses| normalize_lines(language, parse_lines(guesses)));
+        let issues = validate(language, &answers, guesses.as_deref());
+        if !issues.is_empty() {
+            return Err(WordListError::Invalid(issues));
+        }
+        let guesses = guesses.unwrap_or_else(|| answers.clone());
+        Ok(Self {
+            word_length: answers[0].1.chars().count(),
+            answers: answers.into_iter().map(|(_, word)| word).collect(),
+            allowed: guesses.into_iter().map(|(_, word)| word).collect(),
+            language,
+        })
+    }
+
+    pub fn from_files(
+        language: &'static Language,
+        answers: &Path,
+        guesses: Option<&Path>,
+    ) -> Result<Self, WordListError> {
+        let answers = fs::read_to_string(answers)?;
+        let guesses = guesses.map(fs::read_to_string).transpose()?;
+        Self::parse(language, &answers, guesses.as_deref())
+    }
+
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    pub fn allowed(&self) -> &[String] {
+        &self.allowed
+    }
+
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    pub fn language(&self) -> &'static Language {
+        self.language
+    }
+
+    pub fn answer_refs(&self) -> Vec<&str> {
+        self.answers.iter().map(|s| s.as_str()).collect()
+    }
+
+    pub fn allowed_refs(&self) -> Vec<&str> {
+        self.allowed.iter().map(|s| s.as_str()).collect()
+    }
+}
+
+pub fn parse_lines(text: &str) -> Vec<(usize, String)> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line, word)| (line, word.to_lowercase()))
+        .collect()
+}
+
+/// Folds accents the way `language` does. Words that can't be normalized are
+/// kept as they are so `validate` can report them.
+pub fn normalize_lines(language: &Language, lines: Vec<(usize, String)>) -> Vec<(usize, String)> {
+    lines
+        .into_iter()
+        .map(|(line, word)| (line, language.normalize(&word).unwrap_or(word)))
+        .collect()
+}
+
+/// Checks both lists and returns every problem found. The word length is
+/// taken from the first answer. Without a guesses list only the answers are
+/// checked.
+pub fn validate(
+    language: &Language,
+    answers: &[(usize, String)],
+    guesses: Option<&[(usize, String)]>,
+) -> Vec<WordListIssue> {
+    let mut issues = vec![];
+    let expected = match answers.first() {
+        Some((_, word)) => word.chars().count(),
+        None => {
+            issues.push(WordListIssue::Empty(ListKind::Answers));
+            return issues;
+        }
+    };
+    let mut lists = vec![(ListKind::Answers, answers)];
+    if let Some(guesses) = guesses {
+        if guesses.is_empty() {
+            issues.push(WordListIssue::Empty(ListKind::Guesses));
+        }
+        lists.push((ListKind::Guesses, guesses));
+    }
+    for (list, words) in lists {
+        let mut seen = HashSet::new();
+        for (line, word) in words {
+            let (line, word) = (*line, word.clone());
+            if !word.chars().all(|c| language.contains(c)) {
+                issues.push(WordListIssue::InvalidCharacter { list, line, word });
+            } else if word.chars().count() != expected {
+                issues.push(WordListIssue::WrongLength {
+                    list,
+                    line,
+                    word,
+                    expected,
+                });
+            } else if !seen.insert(word.clone()) {
+                issues.push(WordListIssue::Duplicate { list, line, word });
+            }
+        }
+    }
+    if let Some(guesses) = guesses {
+        let allowed: HashSet<&String> = guesses.iter().map(|(_, word)| word).collect();
+        for (line, word) in answers {
+            if !allowed.contains(word) {
+                issues.push(WordListIssue::AnswerNotAllowed {
+                    line: *line,
+                    word: word.clone(),
+                });
+            }
+        }
+    }
+    issues
+}
+
+pub fn diff(old: &[String], new: &[String]) -> WordListDiff {
+    let old_words: HashSet<&String> = old.iter().collect();
+    let new_words: HashSet<&String> = new.iter().collect();
+    WordListDiff {
+        added: new
+            .iter()
+            .filter(|word| !old_words.contains(word))
+            .cloned()
+            .collect(),
+        removed: old
+            .iter()
+            .filter(|word| !new_words.contains(word))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Matches `word` against a pattern like `c?a?e`, where `?`, `_` and `.`
+/// stand for any letter.
+pub fn matches_pattern(word: &str, pattern: &str) -> bool {
+    word.chars().count() == pattern.chars().count()
+        && word
+            .chars()
+            .zip(pattern.chars())
+            .all(|(c, p)| matches!(p, '?' | '_' | '.') || c == p)
+}
+
+#[cfg(test)]
+mod test_word_list {
+    use super::{diff, matches_pattern, ListKind, WordList, WordListError, WordListIssue};
+    use crate::language::{ENGLISH, SPANISH};
+
+    fn issues(answers: &str, guesses: Option<&str>) -> Vec<WordListIssue> {
+        match WordList::parse(&ENGLISH, answers, guesses) {
+            Err(WordListError::Invalid(issues)) => issues,
+            other => panic!("expected invalid word list, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_answers_and_guesses() {
+        let list = WordList::parse(
+            &ENGLISH,
+            "# answers\nCRATE\n\nslate\n",
+            Some("crate\nslate\nwould"),
+        )
+        .unwrap();
+        assert_eq!(list.answers(), ["crate", "slate"]);
+        assert_eq!(list.allowed(), ["crate", "slate", "would"]);
+        assert_eq!(list.word_length(), 5);
+    }
+
+    #[test]
+    fn answers_are_guessable_without_a_guess_list() {
+        let list = WordList::parse(&ENGLISH, "planet\nstring", None).unwrap();
+        assert_eq!(list.allowed(), ["planet", "string"]);
+        assert_eq!(list.word_length(), 6);
+    }
+
+    #[test]
+    fn folds_accents_for_the_language() {
+        let list = WordList::parse(&SPANISH, "Árbol\nsueño\nlápiz", None).unwrap();
+        assert_eq!(list.answers(), ["arbol", "sueño", "lapiz"]);
+        assert_eq!(
+            issues("größe", None),
+            vec![WordListIssue::InvalidCharacter {
+                list: ListKind::Answers,
+                line: 1,
+                word: "größe".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_every_issue() {
+        let found = issues("crate\ncrate\nslat3\nmoon", Some("crate\nslat3\nmoon"));
+        assert_eq!(
+            found,
+            vec![
+                WordListIssue::Duplicate {
+                    list: ListKind::Answers,
+                    line: 2,
+                    word: "crate".to_string()
+                },
+                WordListIssue::InvalidCharacter {
+                    list: ListKind::Answers,
+                    line: 3,
+                    word: "slat3".to_string()
+                },
+                WordListIssue::WrongLength {
+                    list: ListKind::Answers,
+                    line: 4,
+                    word: "moon".to_string(),
+                    expected: 5
+                },
+                WordListIssue::InvalidCharacter {
+                    list: ListKind::Guesses,
+                    line: 2,
+                    word: "slat3".to_string()
+                },
+                WordListIssue::WrongLength {
+                    list: ListKind::Guesses,
+                    line: 3,
+                    word: "moon".to_string(),
+                    expected: 5
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn answers_must_be_allowed_guesses() {
+        assert_eq!(
+            issues("crate\nslate", Some("crate")),
+            vec![WordListIssue::AnswerNotAllowed {
+                line: 2,
+                word: "slate".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_answer_issues_once_without_a_guess_list() {
+        assert_eq!(
+            issues("crate\ncrat", None),
+            vec![WordListIssue::WrongLength {
+                list: ListKind::Answers,
+                line: 2,
+                word: "crat".to_string(),
+                expected: 5
+            }]
+        );
+    }
+
+    #[test]
+    fn diffs_lists() {
+        let words = |text: &str| -> Vec<String> { text.split(' ').map(String::from).collect() };
+        let changes = diff(
+            &words("crate slate would"),
+            &words("slate crane would trace"),
+        );
+        assert_eq!(changes.added, words("crane trace"));
+        assert_eq!(changes.removed, words("crate"));
+    }
+
+    #[test]
+    fn matches_wildcard_patterns() {
+        assert!(matches_pattern("crate", "c?a_e"));
+        assert!(matches_pattern("crate", "....."));
+        assert!(!matches_pattern("crate", "s?a?e"));
+        assert!(!matches_pattern("crate", "c?a?"));
+    }
+
+    #[test]
+    fn rejects_empty_answers() {
+        assert_eq!(
+            issues("# nothing here", None),
+            vec![WordListIssue::Empty(ListKind::Answers)]
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use gloo::worker::Registrable;
+use wordle_ui::solver_worker::SolverWorker;
+
+fn main() {
+    SolverWorker::registrar().register();
+}
@@ -0,0 +1,335 @@
+use super::guess_board::GuessBoard;
+use gloo::timers::callback::Interval;
+use gloo::worker::WorkerBridge;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use wordle_game::solver::SolverStep;
+use wordle_game::solver_strategy::{STRATEGY_NAMES, WEIGHTED_ENTROPY};
+use wordle_game::types::{GameRules, LetterState};
+use wordle_ui::solver_worker::{SolverInput, SolverOutput, SolverWorker};
+use yew::prelude::*;
+
+const SPEEDS: [(&str, u32); 3] = [("slow", 2500), ("normal", 1200), ("fast", 400)];
+
+#[derive(Properties, PartialEq)]
+pub struct BotPlayerProps {
+    pub solver: WorkerBridge<SolverWorker>,
+    pub max_word_length: usize,
+    pub rules: GameRules,
+    pub daily_secret: Option<String>,
+    pub on_close: Callback<()>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SecretChoice {
+    Random,
+    Daily,
+    Typed,
+}
+
+impl SecretChoice {
+    fn name(self) -> &'static str {
+        match self {
+            SecretChoice::Random => "random",
+            SecretChoice::Daily => "daily",
+            SecretChoice::Typed => "typed",
+        }
+    }
+}
+
+pub enum BotPlayerMessage {
+    SetStrategy(String),
+    SetSecretChoice(String),
+    SetTypedSecret(String),
+    SetSpeed(String),
+    Watch,
+    Solved(SolverOutput),
+    Tick,
+    TogglePause,
+    Step,
+}
+
+struct Playback {
+    secret_word: String,
+    steps: Vec<SolverStep>,
+    shown: usize,
+}
+
+pub struct BotPlayer {
+    solver: WorkerBridge<SolverWorker>,
+    strategy: String,
+    secret_choice: SecretChoice,
+    typed_secret: String,
+    speed: u32,
+    playback: Option<Playback>,
+    timer: Option<Interval>,
+    thinking: bool,
+    error: Option<String>,
+}
+
+impl BotPlayer {
+    fn watch(&mut self, ctx: &Context<Self>) {
+        let secret_word = match self.secret_choice {
+            SecretChoice::Random => None,
+            SecretChoice::Daily => ctx.props().daily_secret.clone(),
+            SecretChoice::Typed if self.typed_secret.is_empty() => {
+                self.error = Some("type a secret word".to_string());
+                return;
+            }
+            SecretChoice::Typed => Some(self.typed_secret.clone()),
+        };
+        self.timer = None;
+        self.playback = None;
+        self.error = None;
+        self.thinking = true;
+        self.solver.send(SolverInput::Watch {
+            secret_word,
+            strategy: self.strategy.clone(),
+            rules: ctx.props().rules,
+        });
+    }
+
+    fn play(&mut self, ctx: &Context<Self>) {
+        let link = ctx.link().clone();
+        self.timer = Some(Interval::new(self.speed, move || {
+            link.send_message(BotPlayerMessage::Tick)
+        }));
+    }
+
+    fn advance(&mut self) {
+        if let Some(playback) = &mut self.playback {
+            playback.shown = (playback.shown + 1).min(playback.steps.len());
+            if playback.shown == playback.steps.len() {
+                self.timer = None;
+            }
+        }
+    }
+
+    fn view_playback(&self, ctx: &Context<Self>, playback: &Playback) -> Html {
+        let shown = &playback.steps[..playback.shown];
+        let finished = playback.shown == playback.steps.len();
+        let outcome = if !finished {
+            html! {}
+        } else if shown.last().is_some_and(|step| {
+            step.result
+                .iter()
+                .all(|(_, state)| *state == LetterState::CorrectPlacement)
+        }) {
+            html! { <div class="bot-outcome">{format!("solved in {}", shown.len())}</div> }
+        } else {
+            html! {
+                <div class="bot-outcome">
+                    {format!("missed {}", playback.secret_word.to_uppercase())}
+                </div>
+            }
+        };
+        html! {
+            <>
+            <div class="bot-playback">
+                <button
+                    class="toolbar-button"
+                    disabled={finished}
+                    onclick={ctx.link().callback(|_| BotPlayerMessage::TogglePause)}
+                >
+                    {if self.timer.is_some() { "pause" } else { "play" }}
+                </button>
+                <button
+                    class="toolbar-button"
+                    disabled={finished}
+                    onclick={ctx.link().callback(|_| BotPlayerMessage::Step)}
+                >
+                    {"step"}
+                </button>
+            </div>
+            <GuessBoard
+                max_word_length={ctx.props().max_word_length}
+                max_guesses={ctx.props().rules.max_guesses}
+                guesses={shown.iter().map(|step| step.result.clone()).collect::<Vec<_>>()}
+                current_guess={String::new()}
+            />
+            <ol class="bot-steps">
+                {
+                    shown.iter().map(|step| html! {
+                        <li>{step.to_string()}</li>
+                    }).collect::<Html>()
+                }
+            </ol>
+            {outcome}
+            </>
+        }
+    }
+}
+
+impl Component for BotPlayer {
+    type Message = BotPlayerMessage;
+
+    type Properties = BotPlayerProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let on_output = ctx.link().callback(BotPlayerMessage::Solved);
+        Self {
+            solver: ctx
+                .props()
+                .solver
+                .fork(Some(move |output| on_output.emit(output))),
+            strategy: "entropy".to_string(),
+            secret_choice: SecretChoice::Random,
+            typed_secret: String::new(),
+            speed: SPEEDS[1].1,
+            playback: None,
+            timer: None,
+            thinking: false,
+            error: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        use BotPlayerMessage::*;
+        match msg {
+            SetStrategy(strategy) => self.strategy = strategy,
+            SetSecretChoice(name) => {
+                self.secret_choice = [
+                    SecretChoice::Random,
+                    SecretChoice::Daily,
+                    SecretChoice::Typed,
+                ]
+                .into_iter()
+                .find(|choice| choice.name() == name)
+                .unwrap_or(SecretChoice::Random)
+            }
+            SetTypedSecret(word) => self.typed_secret = word,
+            SetSpeed(name) => {
+                if let Some((_, speed)) = SPEEDS.iter().find(|(speed, _)| *speed == name) {
+                    self.speed = *speed;
+                }
+                if self.timer.is_some() {
+                    self.play(ctx);
+                }
+            }
+            Watch => self.watch(ctx),
+            Solved(SolverOutput::Watch { secret_word, steps }) => {
+                self.thinking = false;
+                self.playback = Some(Playback {
+                    secret_word,
+                    steps,
+                    shown: 0,
+                });
+                self.play(ctx);
+            }
+            Solved(SolverOutput::Error(err)) => {
+                self.thinking = false;
+                self.error = Some(err);
+            }
+            Solved(_) => return false,
+            Tick => self.advance(),
+            TogglePause => {
+                if self.timer.take().is_none() {
+                    self.play(ctx);
+                }
+            }
+            Step => {
+                self.timer = None;
+                self.advance();
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let on_close = ctx.props().on_close.clone();
+        let mut secret_choices = vec![SecretChoice::Random, SecretChoice::Typed];
+        if ctx.props().daily_secret.is_some() {
+            secret_choices.insert(1, SecretChoice::Daily);
+        }
+        html! {
+            <div class="bot-player">
+                <div class="bot-controls">
+                    <label>
+                        {"strategy"}
+                        <select
+                            class="toolbar-select"
+                            onchange={ctx.link().callback(|e: Event| BotPlayerMessage::SetStrategy(
+                                e.target_unchecked_into::<HtmlSelectElement>().value()
+                            ))}
+                        >
+                            {
+                                // there are no word frequencies to weigh answers with
+                                STRATEGY_NAMES.iter().filter(|name| **name != WEIGHTED_ENTROPY).map(|name| html! {
+                                    <option value={*name} selected={*name == self.strategy}>
+                                        {name}
+                                    </option>
+                                }).collect::<Html>()
+                            }
+                        </select>
+                    </label>
+                    <label>
+                        {"secret"}
+                        <select
+                            class="toolbar-select"
+                            onchange={ctx.link().callback(|e: Event| BotPlayerMessage::SetSecretChoice(
+                                e.target_unchecked_into::<HtmlSelectElement>().value()
+                            ))}
+                        >
+                            {
+                                secret_choices.into_iter().map(|choice| html! {
+                                    <option value={choice.name()} selected={choice == self.secret_choice}>
+                                        {choice.name()}
+                                    </option>
+                                }).collect::<Html>()
+                            }
+                        </select>
+                    </label>
+                    if self.secret_choice == SecretChoice::Typed {
+                        <input
+                            class="bot-secret"
+                            type="text"
+                            maxlength={ctx.props().max_word_length.to_string()}
+                            value={self.typed_secret.clone()}
+                            oninput={ctx.link().callback(|e: InputEvent| BotPlayerMessage::SetTypedSecret(
+                                e.target_unchecked_into::<HtmlInputElement>().value()
+                            ))}
+                        />
+                    }
+                    <label>
+                        {"speed"}
+                        <select
+                            class="toolbar-select"
+                            onchange={ctx.link().callback(|e: Event| BotPlayerMessage::SetSpeed(
+                                e.target_unchecked_into::<HtmlSelectElement>().value()
+                            ))}
+                        >
+                            {
+                                SPEEDS.iter().map(|(name, speed)| html! {
+                                    <option value={*name} selected={*speed == self.speed}>
+                                        {name}
+                                    </option>
+                                }).collect::<Html>()
+                            }
+                        </select>
+                    </label>
+                    <button
+                        class="toolbar-button"
+                        disabled={self.thinking}
+                        onclick={ctx.link().callback(|_| BotPlayerMessage::Watch)}
+                    >
+                        {"watch"}
+                    </button>
+                    <button class="toolbar-button" onclick={Callback::from(move |_| on_close.emit(()))}>
+                        {"close"}
+                    </button>
+                </div>
+                if self.thinking {
+                    <div class="bot-outcome">{"thinking…"}</div>
+                }
+                if let Some(err) = &self.error {
+                    <div class="bot-outcome">{err}</div>
+                }
+                {
+                    self.playback
+                        .as_ref()
+                        .map(|playback| self.view_playback(ctx, playback))
+                        .unwrap_or_default()
+                }
+            </div>
+        }
+    }
+}
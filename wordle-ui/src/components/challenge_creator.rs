@@ -0,0 +1,98 @@
+use web_sys::HtmlInputElement;
+use wordle_game::challenge::{Challenge, MAX_CHALLENGE_GUESSES};
+use wordle_game::game::DEFAULT_MAX_GUESSES;
+use wordle_game::types::GameRules;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ChallengeCreatorProps {
+    pub link: Option<String>,
+    pub on_create: Callback<Challenge>,
+}
+
+#[function_component(ChallengeCreator)]
+pub fn challenge_creator(
+    ChallengeCreatorProps { link, on_create }: &ChallengeCreatorProps,
+) -> Html {
+    let secret_word = use_state(String::new);
+    let hard_mode = use_state(|| false);
+    let max_guesses = use_state(|| DEFAULT_MAX_GUESSES);
+
+    let on_secret = {
+        let secret_word = secret_word.clone();
+        Callback::from(move |e: InputEvent| {
+            secret_word.set(e.target_unchecked_into::<HtmlInputElement>().value())
+        })
+    };
+    let on_hard_mode = {
+        let hard_mode = hard_mode.clone();
+        Callback::from(move |e: Event| {
+            hard_mode.set(e.target_unchecked_into::<HtmlInputElement>().checked())
+        })
+    };
+    let on_max_guesses = {
+        let max_guesses = max_guesses.clone();
+        Callback::from(move |e: Event| {
+            if let Ok(value) = e
+                .target_unchecked_into::<HtmlInputElement>()
+                .value()
+                .parse()
+            {
+                max_guesses.set(MAX_CHALLENGE_GUESSES.min(value).max(1));
+            }
+        })
+    };
+    let on_submit = {
+        let on_create = on_create.clone();
+        let secret_word = secret_word.clone();
+        let hard_mode = hard_mode.clone();
+        let max_guesses = max_guesses.clone();
+        Callback::from(move |_| {
+            on_create.emit(Challenge {
+                secret_word: secret_word.trim().to_string(),
+                rules: GameRules {
+                    max_guesses: *max_guesses,
+                    hard_mode: *hard_mode,
+                },
+            })
+        })
+    };
+    html! {
+        <div class="challenge-creator">
+            <label>
+                {"secret word"}
+                <input
+                    class="challenge-secret"
+                    type="password"
+                    autocomplete="off"
+                    value={(*secret_word).clone()}
+                    oninput={on_secret}
+                />
+            </label>
+            <label>
+                {"guesses"}
+                <input
+                    type="number"
+                    min="1"
+                    max={MAX_CHALLENGE_GUESSES.to_string()}
+                    value={max_guesses.to_string()}
+                    onchange={on_max_guesses}
+                />
+            </label>
+            <label>
+                <input type="checkbox" checked={*hard_mode} onchange={on_hard_mode}/>
+                {"hard mode"}
+            </label>
+            <button
+                class="toolbar-button"
+                disabled={secret_word.trim().is_empty()}
+                onclick={on_submit}
+            >
+                {"create link"}
+            </button>
+            if let Some(link) = link {
+                <input class="challenge-link" type="text" readonly=true value={link.clone()}/>
+            }
+        </div>
+    }
+}
@@ -1,25 +1,73 @@
+use super::bot_player::BotPlayer;
+use super::challenge_creator::ChallengeCreator;
+use super::game_review::GameReview;
 use super::guess_board::GuessBoard;
 use super::keyboard::Keyboard;
+use super::settings::SettingsModal;
+use super::stats_modal::StatsModal;
+use super::util::{guess_label, theme_classes};
 use super::word_hints::WordHintsPopover;
-use gloo_events::EventListener;
+use super::word_list_upload::WordListUpload;
+use crate::keyboard_layout::KeyboardLayout;
+use crate::storage::{self, LocalStatsStore, Settings, StoredGame, StoredWordList};
+use gloo::worker::{Spawnable, WorkerBridge};
+use gloo_events::{EventListener, EventListenerOptions};
 use gloo_utils::window;
-use wasm_bindgen::JsCast;
 use wasm_bindgen::UnwrapThrowExt;
-use wordle_game::constraint::word_matches;
-use wordle_game::dictionary::PICKABLE_WORDS;
-use wordle_game::game::WordleGame;
-use wordle_game::types::{GameCondition, Guesses};
+use wasm_bindgen::{JsCast, JsValue};
+use wordle_game::analysis::GameAnalysis;
+use wordle_game::challenge::Challenge;
+use wordle_game::daily;
+use wordle_game::game::{WordleGame, DEFAULT_MAX_GUESSES};
+use wordle_game::language::{Language, ENGLISH};
+use wordle_game::letter_frequency::LetterFrequencies;
+use wordle_game::share::themed_emoji_grid;
+use wordle_game::stats::{GameRecord, Stats, StatsStore};
+use wordle_game::suggestions::{Suggestion, SuggestionMethod};
+use wordle_game::types::{GameCondition, GameRules, Guesses};
+use wordle_game::word_list::{WordList, WordListError};
+use wordle_ui::solver_worker::{SolverInput, SolverOutput, SolverWorker, WORKER_PATH};
 use yew::events::KeyboardEvent;
 use yew::prelude::*;
-const MAX_GUESSES: usize = 6;
-const MAX_WORD_LENGTH: usize = 5;
+/// Challenge links carry their code in the fragment, which isn't sent to
+/// the server.
+const CHALLENGE_PREFIX: &str = "#challenge=";
+
+struct Hints {
+    suggestions: Vec<Suggestion>,
+    frequencies: LetterFrequencies,
+}
 
 pub struct Game {
     current_guess: String,
     game: WordleGame,
+    puzzle_number: Option<u64>,
+    challenge: bool,
+    word_list: WordList,
+    custom_word_list: bool,
+    show_word_list_upload: bool,
+    /// Length of the uploaded word list, even while it isn't played.
+    uploaded_word_length: Option<usize>,
+    settings: Settings,
+    show_settings: bool,
+    show_bot: bool,
+    show_challenge_creator: bool,
+    challenge_link: Option<String>,
+    /// A challenge opened during another game, started by the next new game.
+    pending_challenge: Option<WordleGame>,
+    review: Option<GameAnalysis>,
+    review_strategy: String,
+    solver: WorkerBridge<SolverWorker>,
+    hint_method: SuggestionMethod,
+    /// `None` while the worker is ranking.
+    hints: Option<Hints>,
+    stats: Stats,
     key_listener: Option<EventListener>,
     game_message: Option<String>,
     message_key: u8,
+    announcement: String,
+    /// Flips with every announcement, so a repeated one is read again.
+    announcement_parity: bool,
 }
 
 pub enum GameMessage {
@@ -27,17 +75,42 @@ pub enum GameMessage {
     DeleteLetter,
     Submit,
     NewGame,
+    EnablePractice,
+    Undo,
+    Redo,
+    ToggleWordListUpload,
+    UploadWordList(String, Option<String>),
+    ResetWordList,
+    SetLanguage(String),
+    SetSettings(Settings),
+    ToggleSettings,
+    ToggleReview,
+    SetReviewStrategy(String),
+    ToggleBot,
+    ToggleChallengeCreator,
+    CloseOverlays,
+    CreateChallenge(Challenge),
+    FillGuess(String),
+    SetHintMethod(SuggestionMethod),
+    Solved(SolverOutput),
 }
 
 impl Game {
     fn handle_submit(&mut self) -> bool {
         let s = self.game.secret_word().to_string();
+        let mut guess_result = None;
         match self.game.make_guess(&self.current_guess) {
             Err(err) => {
                 self.set_message(err);
             }
             Ok(_) => {
+                guess_result = self.game.game_state().guesses.last().map(guess_label);
                 self.current_guess = String::new();
+                self.persist_game();
+                self.request_hints();
+                if !self.still_playing() {
+                    self.record_result();
+                }
             }
         };
         if self.game.game_condition() == GameCondition::Win {
@@ -46,16 +119,26 @@ impl Game {
         if self.game.game_condition() == GameCondition::Loss {
             self.set_message(&s);
         }
+        if let Some(guess_result) = guess_result {
+            let outcome = match self.game.game_condition() {
+                GameCondition::Win => " You win!".to_string(),
+                GameCondition::Loss => format!(" The word was {}.", s.to_uppercase()),
+                GameCondition::Playing => String::new(),
+            };
+            self.announce(format!("{}.{}", guess_result, outcome));
+        }
         true
     }
 
     fn handle_add_letter(&mut self, c: char) -> bool {
-        let current_guess_incomplete = self.current_guess.len() < MAX_WORD_LENGTH;
-        if self.still_playing() && current_guess_incomplete && c.is_ascii_alphabetic() {
-            self.current_guess.push(c.to_ascii_lowercase());
-            true
-        } else {
-            false
+        let current_guess_incomplete =
+            self.current_guess.chars().count() < self.word_list.word_length();
+        match self.word_list.language().normalize_char(c) {
+            Some(c) if self.still_playing() && current_guess_incomplete => {
+                self.current_guess.push(c);
+                true
+            }
+            _ => false,
         }
     }
 
@@ -69,7 +152,245 @@ impl Game {
     }
 
     fn handle_new_game(&mut self) -> bool {
-        self.game = Self::new_game();
+        if self.word_list.word_length() != self.settings.word_length {
+            let (word_list, custom) =
+                Self::pick_word_list(Self::load_word_list(), self.settings.word_length);
+            self.word_list = word_list;
+            self.custom_word_list = custom;
+            self.sync_word_list();
+        }
+        let challenge = self.pending_challenge.take();
+        self.challenge = challenge.is_some();
+        let (game, puzzle_number) = match challenge {
+            Some(game) => (game, None),
+            None => Self::new_game(
+                &self.word_list,
+                self.custom_word_list,
+                self.settings.rules(),
+            ),
+        };
+        self.current_guess = String::new();
+        self.game = game;
+        self.puzzle_number = puzzle_number;
+        self.review = None;
+        self.persist_game();
+        self.request_hints();
+        true
+    }
+
+    fn request_hints(&mut self) {
+        self.hints = None;
+        if !self.settings.hints {
+            return;
+        }
+        self.solver.send(SolverInput::Hints {
+            guesses: self.game.game_state().guesses.clone(),
+            method: self.hint_method,
+        });
+    }
+
+    fn handle_toggle_review(&mut self) -> bool {
+        if self.review.take().is_none() {
+            self.request_review();
+        }
+        true
+    }
+
+    fn request_review(&mut self) {
+        self.solver.send(SolverInput::Review {
+            secret_word: self.game.secret_word().to_string(),
+            guesses: self.game.words_already_guessed(),
+            rules: self.game.rules(),
+            strategy: self.review_strategy.clone(),
+        });
+        self.set_message("Reviewing…");
+    }
+
+    fn handle_solved(&mut self, output: SolverOutput) -> bool {
+        match output {
+            SolverOutput::Hints {
+                guesses,
+                method,
+                suggestions,
+            } => {
+                // answers to guesses that have since been undone or played on
+                if method != self.hint_method || &guesses != self.game.game_state().guesses {
+                    return false;
+                }
+                let frequencies = LetterFrequencies::from_words(
+                    suggestions
+                        .iter()
+                        .map(|suggestion| suggestion.word.as_str()),
+                );
+                self.hints = Some(Hints {
+                    suggestions,
+                    frequencies,
+                });
+            }
+            SolverOutput::Review { strategy, analysis } => {
+                if self.still_playing()
+                    || analysis.secret_word != self.game.secret_word()
+                    || strategy != self.review_strategy
+                {
+                    return false;
+                }
+                self.review = Some(analysis);
+            }
+            SolverOutput::Score {
+                secret_word,
+                scores,
+            } => {
+                if let Err(err) = LocalStatsStore.add_scores(&secret_word, scores) {
+                    log::warn!("unable to record scores: {}", err);
+                }
+                self.stats = Self::load_stats();
+            }
+            SolverOutput::Watch { .. } => return false,
+            SolverOutput::Error(err) => log::warn!("solver worker: {}", err),
+        }
+        true
+    }
+
+    fn sync_word_list(&self) {
+        let (answers, guesses) = match self.custom_word_list.then(storage::load_word_list) {
+            Some(Some(StoredWordList { answers, guesses })) => (Some(answers), guesses),
+            _ => (None, None),
+        };
+        self.solver.send(SolverInput::UseWordList {
+            language: self.word_list.language().code.to_string(),
+            answers,
+            guesses,
+        });
+    }
+
+    fn handle_enable_practice(&mut self) -> bool {
+        // the daily would never be marked played, and could be replayed
+        if self.puzzle_number.is_some() {
+            self.set_message("Practice is only for random games");
+            return true;
+        }
+        self.game.enable_practice_mode();
+        self.persist_game();
+        true
+    }
+
+    fn handle_undo(&mut self, redo: bool) -> bool {
+        let result = if redo {
+            self.game.redo()
+        } else {
+            self.game.undo()
+        };
+        match result {
+            Err(err) => self.set_message(err),
+            Ok(_) => {
+                self.persist_game();
+                self.request_hints();
+            }
+        }
+        true
+    }
+
+    fn handle_upload_word_list(&mut self, answers: String, guesses: Option<String>) -> bool {
+        match WordList::parse(self.word_list.language(), &answers, guesses.as_deref()) {
+            Err(err) => self.set_message(&word_list_error_message(&err)),
+            Ok(word_list) => {
+                storage::save_word_list(&StoredWordList { answers, guesses });
+                self.uploaded_word_length = Some(word_list.word_length());
+                self.set_word_length(word_list.word_length());
+                self.use_word_list(word_list, true);
+            }
+        }
+        true
+    }
+
+    fn handle_reset_word_list(&mut self) -> bool {
+        storage::clear_word_list();
+        self.uploaded_word_length = None;
+        let word_list = self.word_list.language().word_list();
+        self.set_word_length(word_list.word_length());
+        self.use_word_list(word_list, false);
+        true
+    }
+
+    fn handle_set_language(&mut self, code: &str) -> bool {
+        let language = match Language::by_code(code) {
+            Some(language) => language,
+            None => return false,
+        };
+        storage::set_language(language);
+        storage::clear_word_list();
+        self.uploaded_word_length = None;
+        let word_list = language.word_list();
+        self.set_word_length(word_list.word_length());
+        self.use_word_list(word_list, false);
+        true
+    }
+
+    fn use_word_list(&mut self, word_list: WordList, custom: bool) {
+        self.word_list = word_list;
+        self.custom_word_list = custom;
+        self.show_word_list_upload = false;
+        self.sync_word_list();
+        self.handle_new_game();
+    }
+
+    fn set_word_length(&mut self, word_length: usize) {
+        self.settings.word_length = word_length;
+        storage::set_settings(&self.settings);
+    }
+
+    /// Rule and word length changes apply to the current game if it has no
+    /// guesses yet, else from the next one. The daily keeps the default number
+    /// of guesses.
+    fn handle_set_settings(&mut self, settings: Settings) -> bool {
+        storage::set_settings(&settings);
+        let old = std::mem::replace(&mut self.settings, settings);
+        if old.word_length != self.settings.word_length {
+            if self.still_playing() && !self.game.game_state().guesses.is_empty() {
+                self.set_message("Word length changes from the next game");
+            } else {
+                self.handle_new_game();
+            }
+        } else if old.rules() != self.settings.rules() && !self.challenge {
+            let rules = match self.puzzle_number {
+                Some(_) => daily_rules(self.settings.rules()),
+                None => self.settings.rules(),
+            };
+            match apply_rules(&mut self.game, rules) {
+                Ok(()) => self.persist_game(),
+                Err(_) => self.set_message("Rules change from the next game"),
+            }
+            if self.puzzle_number.is_some() && old.max_guesses != self.settings.max_guesses {
+                self.set_message(&format!(
+                    "The daily always allows {} guesses",
+                    DEFAULT_MAX_GUESSES
+                ));
+            }
+        }
+        if old.hints != self.settings.hints {
+            self.request_hints();
+        }
+        true
+    }
+
+    fn handle_create_challenge(&mut self, challenge: Challenge) -> bool {
+        match challenge.start(&self.word_list) {
+            Err(err) => self.set_message(&err.to_string()),
+            Ok(game) => {
+                let challenge = Challenge {
+                    secret_word: game.secret_word().to_string(),
+                    rules: game.rules(),
+                };
+                let location = window().location();
+                self.challenge_link = Some(format!(
+                    "{}{}{}{}",
+                    location.origin().unwrap_or_default(),
+                    location.pathname().unwrap_or_default(),
+                    CHALLENGE_PREFIX,
+                    challenge.encode()
+                ));
+            }
+        }
         true
     }
 
@@ -80,11 +401,228 @@ impl Game {
     fn set_message(&mut self, msg: &str) {
         self.game_message.replace(msg.to_string());
         self.message_key = self.message_key.wrapping_add(1);
+        self.announce(msg.to_string());
+    }
+
+    fn announce(&mut self, announcement: String) {
+        self.announcement = announcement;
+        self.announcement_parity = !self.announcement_parity;
+    }
+
+    fn handle_close_overlays(&mut self) -> bool {
+        self.show_settings = false;
+        self.show_word_list_upload = false;
+        self.show_challenge_creator = false;
+        self.show_bot = false;
+        self.review = None;
+        true
+    }
+
+    fn persist_game(&self) {
+        storage::save_game(&StoredGame {
+            game: self.game.save(),
+            puzzle_number: self.puzzle_number,
+            challenge: self.challenge,
+        });
+    }
+
+    fn record_result(&mut self) {
+        // challenges are picked by a friend, often with other rules
+        if self.game.is_practice() || self.challenge {
+            return;
+        }
+        let record = GameRecord {
+            secret_word: self.game.secret_word().to_string(),
+            num_guesses: self.game.game_state().guesses.len(),
+            result: self.game.game_condition(),
+            puzzle_number: self.puzzle_number,
+            // scoring compares every allowed guess, so the worker adds them
+            scores: None,
+        };
+        if let Err(err) = LocalStatsStore.record(record) {
+            log::warn!("unable to record result: {}", err);
+        }
+        self.solver.send(SolverInput::Score {
+            secret_word: self.game.secret_word().to_string(),
+            guesses: self.game.words_already_guessed(),
+            rules: self.game.rules(),
+        });
+        self.stats = Self::load_stats();
+        if let Some(puzzle_number) = self.puzzle_number {
+            storage::set_last_daily_played(puzzle_number);
+        }
+    }
+
+    fn load_stats() -> Stats {
+        LocalStatsStore.stats().unwrap_or_else(|err| {
+            log::warn!("unable to load stats: {}", err);
+            Stats::default()
+        })
     }
 
-    fn new_game() -> WordleGame {
-        WordleGame::new_with_random_secret_word(PICKABLE_WORDS)
+    fn load_word_list() -> Option<WordList> {
+        let stored = storage::load_word_list()?;
+        match WordList::parse(
+            storage::language(),
+            &stored.answers,
+            stored.guesses.as_deref(),
+        ) {
+            Ok(word_list) => Some(word_list),
+            Err(err) => {
+                log::warn!("discarding saved word list: {}", err);
+                None
+            }
+        }
+    }
+
+    fn restore_game(word_list: &WordList) -> Option<(WordleGame, StoredGame)> {
+        let stored = storage::load_game()?;
+        match WordleGame::restore_from_word_list(word_list, &stored.game) {
+            Ok(game) => Some((game, stored)),
+            Err(err) => {
+                log::warn!("discarding saved game: {}", err);
+                None
+            }
+        }
+    }
+
+    /// Today's daily puzzle until it has been played, then random games.
+    /// Custom word lists and other languages only get random games.
+    fn new_game(word_list: &WordList, custom: bool, rules: GameRules) -> (WordleGame, Option<u64>) {
+        let today = todays_puzzle_number();
+        let has_daily = !custom && word_list.language() == &ENGLISH;
+        let (mut game, puzzle_number) = if !has_daily || storage::last_daily_played() == Some(today)
+        {
+            (
+                WordleGame::from_word_list_with_random_secret_word(word_list),
+                None,
+            )
+        } else {
+            let secret_word = daily::daily_secret_word(today);
+            (
+                WordleGame::from_word_list(word_list, secret_word),
+                Some(today),
+            )
+        };
+        let rules = match puzzle_number {
+            Some(_) => daily_rules(rules),
+            None => rules,
+        };
+        apply_rules(&mut game, rules).expect("new games have no guesses");
+        (game, puzzle_number)
+    }
+
+    fn share_text(&self) -> String {
+        let state = self.game.game_state();
+        let label = match self.puzzle_number {
+            Some(puzzle_number) => format!("#{}", puzzle_number),
+            None if self.challenge => "challenge".to_string(),
+            None => "random".to_string(),
+        };
+        let score = match state.condition {
+            GameCondition::Win => state.guesses.len().to_string(),
+            _ => "X".to_string(),
+        };
+        let rules = self.game.rules();
+        format!(
+            "simpl {} {}/{}{}\n\n{}",
+            label,
+            score,
+            rules.max_guesses,
+            if rules.hard_mode { "*" } else { "" },
+            themed_emoji_grid(state.guesses, self.settings.palette, self.settings.theme)
+        )
     }
+
+    fn pick_word_list(uploaded: Option<WordList>, word_length: usize) -> (WordList, bool) {
+        match uploaded {
+            Some(word_list) if word_list.word_length() == word_length => (word_list, true),
+            _ => (storage::language().word_list(), false),
+        }
+    }
+}
+
+/// Fails once `game` has guesses, unless the rules are unchanged.
+fn apply_rules(game: &mut WordleGame, rules: GameRules) -> Result<(), &'static str> {
+    game.set_hard_mode(rules.hard_mode)?;
+    game.set_max_guesses(rules.max_guesses)
+}
+
+/// Everyone plays the daily with the same number of guesses, so results
+/// compare. Hard mode only makes it harder, and shows in the share text.
+fn daily_rules(rules: GameRules) -> GameRules {
+    GameRules {
+        max_guesses: DEFAULT_MAX_GUESSES,
+        ..rules
+    }
+}
+
+fn builtin_word_length(language: &Language) -> usize {
+    language
+        .answers
+        .as_slice()
+        .first()
+        .and_then(|word| language.normalize(word))
+        .map_or(storage::DEFAULT_WORD_LENGTH, |word| word.chars().count())
+}
+
+fn word_list_error_message(err: &WordListError) -> String {
+    match err {
+        WordListError::Invalid(issues) if issues.len() > 1 => {
+            format!("{} (+{} more)", issues[0], issues.len() - 1)
+        }
+        WordListError::Invalid(issues) if !issues.is_empty() => issues[0].to_string(),
+        err => err.to_string(),
+    }
+}
+
+/// The code of the challenge link the page was opened with, removed from
+/// the address so reloading continues the game instead of restarting it.
+fn take_challenge_code() -> Option<String> {
+    let location = window().location();
+    let code = location
+        .hash()
+        .ok()?
+        .strip_prefix(CHALLENGE_PREFIX)?
+        .to_string();
+    let url = format!(
+        "{}{}",
+        location.pathname().unwrap_or_default(),
+        location.search().unwrap_or_default()
+    );
+    if let Err(err) = gloo_utils::history().replace_state_with_url(&JsValue::NULL, "", Some(&url)) {
+        log::warn!("unable to clear the challenge from the address: {:?}", err);
+    }
+    Some(code)
+}
+
+/// Whether a key belongs to the focused control rather than the game: keys
+/// typed into a field, like a challenge's secret, and ENTER or space on a
+/// button focused from the keyboard. ESC always closes overlays.
+fn is_for_focused_control(event: &web_sys::KeyboardEvent) -> bool {
+    let target = match event.target() {
+        Some(target) => target,
+        None => return false,
+    };
+    let key = event.key();
+    if key == "Escape" {
+        return false;
+    }
+    target.has_type::<web_sys::HtmlInputElement>()
+        || target.has_type::<web_sys::HtmlSelectElement>()
+        || target.has_type::<web_sys::HtmlTextAreaElement>()
+        || (target.has_type::<web_sys::HtmlButtonElement>()
+            && (key == "Enter" || key == " ")
+            && target
+                .unchecked_ref::<web_sys::Element>()
+                .matches(":focus-visible")
+                .unwrap_or(false))
+}
+
+fn todays_puzzle_number() -> u64 {
+    let now = js_sys::Date::new_0();
+    let local_millis = now.get_time() - now.get_timezone_offset() * 60_000.0;
+    daily::puzzle_number((local_millis / 86_400_000.0).floor() as u64)
 }
 
 impl Component for Game {
@@ -92,70 +630,329 @@ impl Component for Game {
 
     type Properties = ();
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
+    fn create(ctx: &Context<Self>) -> Self {
+        let settings = storage::settings();
+        let uploaded = Self::load_word_list();
+        let uploaded_word_length = uploaded.as_ref().map(WordList::word_length);
+        // a word length change waits for the stored game to be over
+        let word_length = storage::load_game().map_or(settings.word_length, |stored| {
+            stored.game.secret_word.chars().count()
+        });
+        let (word_list, custom_word_list) = Self::pick_word_list(uploaded, word_length);
+        let challenge = take_challenge_code()
+            .map(|code| Challenge::decode(&code).and_then(|challenge| challenge.start(&word_list)));
+        let (challenge, challenge_error) = match challenge {
+            Some(Ok(game)) => (Some(game), None),
+            Some(Err(err)) => (None, Some(err)),
+            None => (None, None),
+        };
+        let restored = Self::restore_game(&word_list);
+        let in_progress = restored.as_ref().is_some_and(|(game, _)| {
+            game.game_condition() == GameCondition::Playing && !game.game_state().guesses.is_empty()
+        });
+        // don't throw away a game the player has started, the daily least of all
+        let (challenge, pending_challenge) = match challenge {
+            Some(game) if in_progress => (None, Some(game)),
+            challenge => (challenge, None),
+        };
+        let (game, puzzle_number, is_challenge) = match (challenge, restored) {
+            (Some(game), _) => (game, None, true),
+            (None, Some((game, stored))) => (game, stored.puzzle_number, stored.challenge),
+            (None, None) => {
+                let (game, puzzle_number) =
+                    Self::new_game(&word_list, custom_word_list, settings.rules());
+                (game, puzzle_number, false)
+            }
+        };
+        let solver = {
+            let on_output = ctx.link().callback(GameMessage::Solved);
+            SolverWorker::spawner()
+                .callback(move |output| on_output.emit(output))
+                .spawn(WORKER_PATH)
+        };
+        let mut game = Self {
             current_guess: String::from(""),
-            game: Self::new_game(),
+            game,
+            puzzle_number,
+            challenge: is_challenge,
+            word_list,
+            custom_word_list,
+            show_word_list_upload: false,
+            uploaded_word_length,
+            settings,
+            show_settings: false,
+            show_bot: false,
+            show_challenge_creator: false,
+            challenge_link: None,
+            review: None,
+            review_strategy: "entropy".to_string(),
+            pending_challenge,
+            solver,
+            hint_method: SuggestionMethod::LetterFrequency,
+            hints: None,
+            stats: Self::load_stats(),
             key_listener: None,
             game_message: None,
             message_key: 0,
+            announcement: String::new(),
+            announcement_parity: false,
+        };
+        if let Some(err) = challenge_error {
+            game.set_message(&format!("Unable to start challenge: {}", err));
+        }
+        if game.pending_challenge.is_some() {
+            game.set_message("Finish this game to play the challenge");
         }
+        game.sync_word_list();
+        game.request_hints();
+        game.persist_game();
+        game
     }
 
     fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
         use GameMessage::*;
         match msg {
+            // the bot has the board, and its secret input takes the keys
+            AddLetter(_) | DeleteLetter | Submit if self.show_bot => false,
             AddLetter(c) => self.handle_add_letter(c),
             DeleteLetter => self.handle_delete(),
             Submit => self.handle_submit(),
             NewGame => self.handle_new_game(),
+            EnablePractice => self.handle_enable_practice(),
+            Undo => self.handle_undo(false),
+            Redo => self.handle_undo(true),
+            ToggleWordListUpload => {
+                self.show_word_list_upload = !self.show_word_list_upload;
+                true
+            }
+            UploadWordList(answers, guesses) => self.handle_upload_word_list(answers, guesses),
+            ResetWordList => self.handle_reset_word_list(),
+            SetLanguage(code) => self.handle_set_language(&code),
+            SetSettings(settings) => self.handle_set_settings(settings),
+            ToggleSettings => {
+                self.show_settings = !self.show_settings;
+                true
+            }
+            FillGuess(word) => {
+                if !self.still_playing() {
+                    return false;
+                }
+                self.current_guess = word;
+                true
+            }
+            SetHintMethod(method) => {
+                self.hint_method = method;
+                self.request_hints();
+                true
+            }
+            ToggleReview => self.handle_toggle_review(),
+            SetReviewStrategy(strategy) => {
+                self.review_strategy = strategy;
+                self.request_review();
+                true
+            }
+            ToggleBot => {
+                self.show_bot = !self.show_bot;
+                true
+            }
+            ToggleChallengeCreator => {
+                self.show_challenge_creator = !self.show_challenge_creator;
+                self.challenge_link = None;
+                true
+            }
+            CloseOverlays => self.handle_close_overlays(),
+            CreateChallenge(challenge) => self.handle_create_challenge(challenge),
+            Solved(output) => self.handle_solved(output),
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let guesses: Guesses = self.game.game_state().guesses.clone();
-        let remaining_words: Vec<String> = PICKABLE_WORDS
-            .iter()
-            .filter(|word| word_matches(word, &self.game.game_state()))
-            .map(|word| word.to_string())
-            .collect();
+        let daily_secret = (!self.custom_word_list && self.word_list.language() == &ENGLISH)
+            .then(|| daily::daily_secret_word(todays_puzzle_number()).to_string());
+        let mut word_lengths: Vec<usize> =
+            std::iter::once(builtin_word_length(self.word_list.language()))
+                .chain(self.uploaded_word_length)
+                .collect();
+        word_lengths.sort_unstable();
+        word_lengths.dedup();
+
+        let mut game_mode = match self.puzzle_number {
+            Some(puzzle_number) => format!("daily #{}", puzzle_number),
+            None if self.challenge => "challenge".to_string(),
+            None => "random".to_string(),
+        };
+        if self.custom_word_list {
+            game_mode.push_str(" · custom words");
+        }
+        if self.game.is_practice() {
+            game_mode.push_str(" · practice");
+        }
+        let practice_controls = if self.game.is_practice() {
+            html! {
+                <>
+                <button class="toolbar-button" onclick={ctx.link().callback(|_| GameMessage::Undo)}>
+                    {"undo"}
+                </button>
+                <button class="toolbar-button" onclick={ctx.link().callback(|_| GameMessage::Redo)}>
+                    {"redo"}
+                </button>
+                </>
+            }
+        } else if self.puzzle_number.is_some() {
+            html! {}
+        } else {
+            html! {
+                <button
+                    class="toolbar-button"
+                    onclick={ctx.link().callback(|_| GameMessage::EnablePractice)}
+                >
+                    {"practice"}
+                </button>
+            }
+        };
 
         html! {
-            <div class="game-container">
+            <div class={classes!(
+                "game-container",
+                theme_classes(self.settings.theme, self.settings.palette),
+                (!self.settings.animations).then_some("no-animations"),
+            )}>
+                <div class="game-toolbar">
+                    <div class="game-mode">{game_mode}</div>
+                    {practice_controls}
+                    <button
+                        class="toolbar-button"
+                        aria-expanded={self.show_word_list_upload.to_string()}
+                        onclick={ctx.link().callback(|_| GameMessage::ToggleWordListUpload)}
+                    >
+                        {"words"}
+                    </button>
+                    <button
+                        class="toolbar-button"
+                        aria-expanded={self.show_settings.to_string()}
+                        onclick={ctx.link().callback(|_| GameMessage::ToggleSettings)}
+                    >
+                        {"settings"}
+                    </button>
+                    <button
+                        class="toolbar-button"
+                        aria-expanded={self.show_bot.to_string()}
+                        onclick={ctx.link().callback(|_| GameMessage::ToggleBot)}
+                    >
+                        {"bot"}
+                    </button>
+                    <button
+                        class="toolbar-button"
+                        aria-expanded={self.show_challenge_creator.to_string()}
+                        onclick={ctx.link().callback(|_| GameMessage::ToggleChallengeCreator)}
+                    >
+                        {"challenge"}
+                    </button>
+                </div>
+                if self.show_settings {
+                    <SettingsModal
+                        language={self.word_list.language()}
+                        settings={self.settings.clone()}
+                        {word_lengths}
+                        on_language_change={ctx.link().callback(GameMessage::SetLanguage)}
+                        on_change={ctx.link().callback(GameMessage::SetSettings)}
+                        on_close={ctx.link().callback(|_| GameMessage::ToggleSettings)}
+                    />
+                }
+                if self.show_challenge_creator {
+                    <ChallengeCreator
+                        link={self.challenge_link.clone()}
+                        on_create={ctx.link().callback(GameMessage::CreateChallenge)}
+                    />
+                }
+                {
+                    if self.show_word_list_upload {
+                        html! {
+                            <WordListUpload
+                                is_custom={self.custom_word_list}
+                                on_upload={ctx.link().callback(|(answers, guesses)| GameMessage::UploadWordList(answers, guesses))}
+                                on_reset={ctx.link().callback(|_| GameMessage::ResetWordList)}
+                            />
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
                 <div
                     key={self.message_key}
                     class={classes!("game-message", self.game_message.as_ref().map(|_| "show"))}
+                    aria-hidden="true"
                 >
                     {self.game_message.as_ref().unwrap_or(&"".to_string())}
                 </div>
-                <GuessBoard
-                    max_word_length={MAX_WORD_LENGTH}
-                    max_guesses={MAX_GUESSES}
-                    guesses={guesses}
-                    current_guess={self.current_guess.clone()}
-                />
-                <Keyboard
-                    letter_states={self.game.letter_states()}
-                    on_key_press={ctx.link().callback(GameMessage::AddLetter)}
-                    on_delete={ctx.link().callback(|_| GameMessage::DeleteLetter)}
-                    on_submit={ctx.link().callback(|_| GameMessage::Submit)}
-                />
+                <div class="visually-hidden" role="status" aria-live="polite">
+                    {&self.announcement}
+                    {if self.announcement_parity { "\u{a0}" } else { "" }}
+                </div>
+                if self.show_bot {
+                    <BotPlayer
+                        solver={self.solver.clone()}
+                        max_word_length={self.word_list.word_length()}
+                        rules={self.settings.rules()}
+                        daily_secret={daily_secret}
+                        on_close={ctx.link().callback(|_| GameMessage::ToggleBot)}
+                    />
+                } else {
+                    <>
+                        <GuessBoard
+                            max_word_length={self.word_list.word_length()}
+                            max_guesses={self.game.rules().max_guesses}
+                            guesses={guesses}
+                            current_guess={self.current_guess.clone()}
+                        />
+                        <Keyboard
+                            rows={KeyboardLayout::by_id(&self.settings.keyboard.layout).rows(self.word_list.language())}
+                            enter_on_right={self.settings.keyboard.enter_on_right}
+                            letter_states={self.game.letter_states()}
+                            on_key_press={ctx.link().callback(GameMessage::AddLetter)}
+                            on_delete={ctx.link().callback(|_| GameMessage::DeleteLetter)}
+                            on_submit={ctx.link().callback(|_| GameMessage::Submit)}
+                        />
 
-                {
-                    if self.game.game_condition() == GameCondition::Playing {
-                        html!{
-                            <WordHintsPopover remaining_words={remaining_words}/>
-                        }
-                    } else {
-                        html!{
-                            <button
-                                class="new-game-button"
-                                onclick={ctx.link().callback(|_| GameMessage::NewGame)}
-                            >
-                                {"new game"}
-                            </button>
+                        {
+                            if self.game.game_condition() == GameCondition::Playing && !self.settings.hints {
+                                html!{}
+                            } else if self.game.game_condition() == GameCondition::Playing {
+                                html!{
+                                    <WordHintsPopover
+                                        suggestions={self.hints.as_ref().map(|hints| hints.suggestions.clone())}
+                                        frequencies={self.hints.as_ref().map(|hints| hints.frequencies.clone()).unwrap_or_default()}
+                                        method={self.hint_method}
+                                        on_method_change={ctx.link().callback(GameMessage::SetHintMethod)}
+                                        on_select={ctx.link().callback(GameMessage::FillGuess)}
+                                    />
+                                }
+                            } else if let Some(analysis) = &self.review {
+                                html!{
+                                    <GameReview
+                                        analysis={analysis.clone()}
+                                        strategy={self.review_strategy.clone()}
+                                        on_strategy_change={ctx.link().callback(GameMessage::SetReviewStrategy)}
+                                        on_close={ctx.link().callback(|_| GameMessage::ToggleReview)}
+                                    />
+                                }
+                            } else {
+                                let winning_guess_count = (self.game.game_condition() == GameCondition::Win)
+                                    .then(|| self.game.game_state().guesses.len());
+                                html!{
+                                    <StatsModal
+                                        stats={self.stats.clone()}
+                                        max_guesses={self.game.rules().max_guesses}
+                                        winning_guess_count={winning_guess_count}
+                                        share_text={self.share_text()}
+                                        on_new_game={ctx.link().callback(|_| GameMessage::NewGame)}
+                                        on_review={ctx.link().callback(|_| GameMessage::ToggleReview)}
+                                    />
+                                }
+                            }
                         }
-                    }
+                    </>
                 }
             </div>
         }
@@ -166,15 +963,32 @@ impl Component for Game {
             return;
         }
 
-        let onkeydown = ctx.link().callback(|e: KeyboardEvent| match e.key_code() {
-            8 => GameMessage::DeleteLetter,
-            13 => GameMessage::Submit,
-            c => GameMessage::AddLetter((c as u8) as char),
-        });
-        let listener = EventListener::new(&window(), "keydown", move |event| {
-            let event = event.dyn_ref::<web_sys::KeyboardEvent>().unwrap_throw();
-            onkeydown.emit(event.clone())
-        });
+        let onkeydown = ctx
+            .link()
+            .batch_callback(|e: KeyboardEvent| match e.key().as_str() {
+                "Backspace" => Some(GameMessage::DeleteLetter),
+                "Escape" => Some(GameMessage::CloseOverlays),
+                "Enter" => Some(GameMessage::Submit),
+                key => {
+                    let mut chars = key.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Some(GameMessage::AddLetter(c)),
+                        _ => None,
+                    }
+                }
+            });
+        let options = EventListenerOptions::enable_prevent_default();
+        let listener =
+            EventListener::new_with_options(&window(), "keydown", options, move |event| {
+                let event = event.dyn_ref::<web_sys::KeyboardEvent>().unwrap_throw();
+                if !is_for_focused_control(event) {
+                    // ENTER submits rather than clicking a button the mouse left focused
+                    if event.key() == "Enter" {
+                        event.prevent_default();
+                    }
+                    onkeydown.emit(event.clone())
+                }
+            });
         self.key_listener.replace(listener);
     }
 
@@ -0,0 +1,85 @@
+use web_sys::HtmlSelectElement;
+use wordle_game::analysis::GameAnalysis;
+use wordle_game::solver_strategy::{STRATEGY_NAMES, WEIGHTED_ENTROPY};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct GameReviewProps {
+    pub analysis: GameAnalysis,
+    pub strategy: String,
+    pub on_strategy_change: Callback<String>,
+    pub on_close: Callback<MouseEvent>,
+}
+
+#[function_component(GameReview)]
+pub fn game_review(
+    GameReviewProps {
+        analysis,
+        strategy,
+        on_strategy_change,
+        on_close,
+    }: &GameReviewProps,
+) -> Html {
+    let scores = analysis.scores();
+    let on_strategy = {
+        let on_strategy_change = on_strategy_change.clone();
+        Callback::from(move |e: Event| {
+            on_strategy_change.emit(e.target_unchecked_into::<HtmlSelectElement>().value())
+        })
+    };
+    html! {
+        <div class="stats-modal game-review">
+            <h3>{"review"}</h3>
+            <label class="hints-method">
+                {"solver "}
+                <select onchange={on_strategy}>
+                    {
+                        // there are no word frequencies to weigh answers with
+                        STRATEGY_NAMES.iter().filter(|name| **name != WEIGHTED_ENTROPY).map(|name| html! {
+                            <option value={*name} selected={name == strategy}>
+                                {name}
+                            </option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </label>
+            <table>
+                <tr>
+                    <th>{"guess"}</th>
+                    <th>{"words left"}</th>
+                    <th>{"bits"}</th>
+                    <th>{"solver"}</th>
+                    <th>{"skill"}</th>
+                    <th>{"luck"}</th>
+                </tr>
+                {
+                    analysis.turns.iter().map(|turn| html! {
+                        <tr>
+                            <td class="review-word">{&turn.guess}</td>
+                            <td>{format!("{} → {}", turn.candidates_before, turn.candidates_after)}</td>
+                            <td>{format!("{:.1}", turn.information)}</td>
+                            <td>
+                                if turn.best_guess == turn.guess {
+                                    {"same"}
+                                } else {
+                                    <span class="review-word">{&turn.best_guess}</span>
+                                    {format!(" → {}", turn.best_candidates_after)}
+                                }
+                            </td>
+                            <td>{format!("{:+.1}", turn.skill())}</td>
+                            <td>{format!("{:+.1}", turn.luck())}</td>
+                        </tr>
+                    }).collect::<Html>()
+                }
+                <tr class="review-total">
+                    <td colspan="4">{"total bits"}</td>
+                    <td>{format!("{:+.1}", scores.skill)}</td>
+                    <td>{format!("{:+.1}", scores.luck)}</td>
+                </tr>
+            </table>
+            <button class="new-game-button" onclick={on_close.clone()}>
+                {"back"}
+            </button>
+        </div>
+    }
+}
@@ -1,4 +1,4 @@
-use super::util::letter_state_class;
+use super::util::{letter_label, letter_state_class};
 use wordle_game::types::{Guess, Guesses};
 use yew::prelude::*;
 
@@ -36,7 +36,7 @@ pub fn guess_board(
         .map(|_| html! {<PlaceholderRow max_length={*max_word_length} />});
 
     html! {
-        <div class="guess-board">
+        <div class="guess-board" role="table" aria-label="guesses">
             {
                 guess_rows
                 .chain(current_row)
@@ -55,12 +55,14 @@ pub struct GuessRowProps {
 #[function_component(GuessRow)]
 pub fn guess_row(GuessRowProps { guess }: &GuessRowProps) -> Html {
     html! {
-        <div class="guess-row">
+        <div class="guess-row" role="row">
             {
                 guess.iter().map(
                     |(c, state)| html! {
                         <div
                             class={classes!("guess-tile", letter_state_class(state))}
+                            role="cell"
+                            aria-label={letter_label(*c, Some(state))}
                         >
                             {c}
                         </div>
@@ -84,14 +86,15 @@ pub fn current_guess_row(
         current_guess,
     }: &CurrentGuessRowProps,
 ) -> Html {
-    let num_remaining = *max_length - current_guess.len();
+    let num_remaining = max_length.saturating_sub(current_guess.chars().count());
     let remaining_spaces = (0..num_remaining).map(|_| ' ');
     html! {
-        <div class="guess-row">
+        <div class="guess-row" role="row">
             {
-                current_guess.chars().chain(remaining_spaces).map(
-                    |c| html! {<div class="guess-tile">{c}</div>}
-                ).collect::<Html>()
+                current_guess.chars().chain(remaining_spaces).map(|c| {
+                    let label = if c == ' ' { "empty".to_string() } else { letter_label(c, None) };
+                    html! {<div class="guess-tile" role="cell" aria-label={label}>{c}</div>}
+                }).collect::<Html>()
             }
         </div>
     }
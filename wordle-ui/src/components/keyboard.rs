@@ -3,10 +3,13 @@ use std::collections::HashMap;
 use wordle_game::types::LetterState;
 use yew::prelude::*;
 
-use super::util::letter_state_class;
+use super::util::{letter_label, letter_state_class};
 
 #[derive(Properties, PartialEq)]
 pub struct KeyboardProps {
+    /// ENTER and ⌫ go on the last row.
+    pub rows: Vec<String>,
+    pub enter_on_right: bool,
     pub letter_states: HashMap<char, LetterState>,
     pub on_key_press: Callback<char>,
     pub on_delete: Callback<char>,
@@ -16,53 +19,56 @@ pub struct KeyboardProps {
 #[function_component(Keyboard)]
 pub fn keyboard(
     KeyboardProps {
+        rows,
+        enter_on_right,
         letter_states,
         on_key_press,
         on_delete,
         on_submit,
     }: &KeyboardProps,
 ) -> Html {
-    let state = |c| letter_states.get(&c).cloned();
+    let last_row = rows.len().saturating_sub(1);
+    let submit_key = html! { <SubmitKey on_submit={on_submit.clone()}/> };
+    let delete_key = html! { <DeleteKey on_delete={on_delete.clone()}/> };
+    let (left_key, right_key) = if *enter_on_right {
+        (delete_key, submit_key)
+    } else {
+        (submit_key, delete_key)
+    };
     html! {
-        <div class="keyboard">
-            <div class="row">
-                <Key letter='q' state={state('q')} on_key_press={on_key_press.clone()}/>
-                <Key letter='w' state={state('w')} on_key_press={on_key_press.clone()}/>
-                <Key letter='e' state={state('e')} on_key_press={on_key_press.clone()}/>
-                <Key letter='r' state={state('r')} on_key_press={on_key_press.clone()}/>
-                <Key letter='t' state={state('t')} on_key_press={on_key_press.clone()}/>
-                <Key letter='y' state={state('y')} on_key_press={on_key_press.clone()}/>
-                <Key letter='u' state={state('u')} on_key_press={on_key_press.clone()}/>
-                <Key letter='i' state={state('i')} on_key_press={on_key_press.clone()}/>
-                <Key letter='o' state={state('o')} on_key_press={on_key_press.clone()}/>
-                <Key letter='p' state={state('p')} on_key_press={on_key_press.clone()}/>
-            </div>
-            <div class="row">
-                <Key letter='a' state={state('a')} on_key_press={on_key_press.clone()}/>
-                <Key letter='s' state={state('s')} on_key_press={on_key_press.clone()}/>
-                <Key letter='d' state={state('d')} on_key_press={on_key_press.clone()}/>
-                <Key letter='f' state={state('f')} on_key_press={on_key_press.clone()}/>
-                <Key letter='g' state={state('g')} on_key_press={on_key_press.clone()}/>
-                <Key letter='h' state={state('h')} on_key_press={on_key_press.clone()}/>
-                <Key letter='j' state={state('j')} on_key_press={on_key_press.clone()}/>
-                <Key letter='k' state={state('k')} on_key_press={on_key_press.clone()}/>
-                <Key letter='l' state={state('l')} on_key_press={on_key_press.clone()}/>
-            </div>
-            <div class="row">
-                <SubmitKey on_submit={on_submit.clone()}/>
-                <Key letter='z' state={state('z')} on_key_press={on_key_press.clone()}/>
-                <Key letter='x' state={state('x')} on_key_press={on_key_press.clone()}/>
-                <Key letter='c' state={state('c')} on_key_press={on_key_press.clone()}/>
-                <Key letter='v' state={state('v')} on_key_press={on_key_press.clone()}/>
-                <Key letter='b' state={state('b')} on_key_press={on_key_press.clone()}/>
-                <Key letter='n' state={state('n')} on_key_press={on_key_press.clone()}/>
-                <Key letter='m' state={state('m')} on_key_press={on_key_press.clone()}/>
-                <DeleteKey on_delete={on_delete.clone()}/>
-            </div>
+        <div class="keyboard" role="group" aria-label="keyboard">
+            {
+                rows.iter().enumerate().map(|(i, row)| html! {
+                    <div class="row">
+                        if i == last_row {
+                            {left_key.clone()}
+                        }
+                        {
+                            row.chars().map(|c| html! {
+                                <Key
+                                    letter={c}
+                                    state={letter_states.get(&c).cloned()}
+                                    on_key_press={on_key_press.clone()}
+                                />
+                            }).collect::<Html>()
+                        }
+                        if i == last_row {
+                            {right_key.clone()}
+                        }
+                    </div>
+                }).collect::<Html>()
+            }
         </div>
     }
 }
 
+/// Clicked keys don't take the focus, so that a typed ENTER submits the
+/// guess rather than pressing the last key again. Keys reached with TAB are
+/// still focused.
+fn keep_focus() -> Callback<MouseEvent> {
+    Callback::from(|e: MouseEvent| e.prevent_default())
+}
+
 #[derive(Properties, PartialEq)]
 struct KeyProps {
     letter: char,
@@ -86,6 +92,8 @@ fn key(
     html! {
         <button
             class={classes!("keyboard-key", state_class, classname)}
+            aria-label={letter_label(letter, state.as_ref())}
+            onmousedown={keep_focus()}
             onclick={move |_| on_key_press.emit(letter)}
         >
             { letter }
@@ -104,6 +112,8 @@ fn delete_key(props: &DeleteKeyProps) -> Html {
     html! {
         <button
             class={classes!("keyboard-key", "delete")}
+            aria-label="delete"
+            onmousedown={keep_focus()}
             onclick={move |_| on_delete.emit(' ')}
         >
             { "⌫" }
@@ -122,6 +132,8 @@ fn submit_key(props: &SubmitKeyProps) -> Html {
     html! {
         <button
             class={classes!("keyboard-key", "submit")}
+            aria-label="enter"
+            onmousedown={keep_focus()}
             onclick={move |_| on_delete.emit(' ')}
         >
             { "ENTER" }
@@ -0,0 +1,36 @@
+use wordle_game::letter_frequency::LetterFrequencies;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct LetterHeatmapProps {
+    pub frequencies: LetterFrequencies,
+}
+
+#[function_component(LetterHeatmap)]
+pub fn letter_heatmap(LetterHeatmapProps { frequencies }: &LetterHeatmapProps) -> Html {
+    let positions = 0..frequencies.word_length();
+    html! {
+        <table class="letter-heatmap">
+            <tr>
+                <th></th>
+                {positions.clone().map(|position| html! {<th>{position + 1}</th>}).collect::<Html>()}
+                <th>{"any"}</th>
+            </tr>
+            {frequencies.letters().into_iter().map(|letter| html! {
+                <tr>
+                    <th>{letter.to_uppercase().to_string()}</th>
+                    {positions.clone().map(|position| {
+                        heatmap_cell(frequencies.at(position, letter), frequencies.share_at(position, letter))
+                    }).collect::<Html>()}
+                    {heatmap_cell(frequencies.overall(letter), frequencies.overall_share(letter))}
+                </tr>
+            }).collect::<Html>()}
+        </table>
+    }
+}
+
+fn heatmap_cell(count: usize, share: f64) -> Html {
+    html! {
+        <td style={format!("--share: {:.2}", share)}>{count}</td>
+    }
+}
@@ -1,6 +1,13 @@
 pub mod app;
+pub mod bot_player;
+pub mod challenge_creator;
 pub mod game;
+pub mod game_review;
 pub mod guess_board;
 pub mod keyboard;
+pub mod letter_heatmap;
+pub mod settings;
+pub mod stats_modal;
 mod util;
 pub mod word_hints;
+pub mod word_list_upload;
@@ -0,0 +1,204 @@
+use super::util::use_dialog_focus;
+use crate::keyboard_layout::LAYOUTS;
+use crate::storage::{Settings, MAX_GUESSES_LIMIT};
+use wasm_bindgen::JsCast;
+use web_sys::{EventTarget, HtmlInputElement, HtmlSelectElement};
+use wordle_game::language::{Language, LANGUAGES};
+use wordle_game::share::{Palette, Theme};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct SettingsModalProps {
+    pub language: &'static Language,
+    pub settings: Settings,
+    pub word_lengths: Vec<usize>,
+    pub on_language_change: Callback<String>,
+    pub on_change: Callback<Settings>,
+    pub on_close: Callback<()>,
+}
+
+fn on_setting<T: JsCast + AsRef<EventTarget>>(
+    settings: &Settings,
+    on_change: &Callback<Settings>,
+    apply: impl Fn(&mut Settings, T) + 'static,
+) -> Callback<Event> {
+    let settings = settings.clone();
+    let on_change = on_change.clone();
+    Callback::from(move |e: Event| {
+        let mut settings = settings.clone();
+        apply(&mut settings, e.target_unchecked_into::<T>());
+        on_change.emit(settings);
+    })
+}
+
+fn checkbox(label: &'static str, checked: bool, onchange: Callback<Event>) -> Html {
+    html! {
+        <label>
+            <input type="checkbox" {checked} {onchange}/>
+            {label}
+        </label>
+    }
+}
+
+#[function_component(SettingsModal)]
+pub fn settings_modal(
+    SettingsModalProps {
+        language,
+        settings,
+        word_lengths,
+        on_language_change,
+        on_change,
+        on_close,
+    }: &SettingsModalProps,
+) -> Html {
+    let on_language = {
+        let on_language_change = on_language_change.clone();
+        Callback::from(move |e: Event| {
+            on_language_change.emit(e.target_unchecked_into::<HtmlSelectElement>().value())
+        })
+    };
+    let on_layout = on_setting(
+        settings,
+        on_change,
+        |settings, select: HtmlSelectElement| settings.keyboard.layout = select.value(),
+    );
+    let on_enter_side = on_setting(settings, on_change, |settings, input: HtmlInputElement| {
+        settings.keyboard.enter_on_right = input.checked()
+    });
+    let on_word_length = on_setting(
+        settings,
+        on_change,
+        |settings, select: HtmlSelectElement| {
+            if let Ok(word_length) = select.value().parse() {
+                settings.word_length = word_length;
+            }
+        },
+    );
+    let on_max_guesses = on_setting(settings, on_change, |settings, input: HtmlInputElement| {
+        if let Ok(max_guesses) = input.value().parse::<usize>() {
+            settings.max_guesses = max_guesses.clamp(1, MAX_GUESSES_LIMIT);
+        }
+    });
+    let on_theme = on_setting(
+        settings,
+        on_change,
+        |settings, select: HtmlSelectElement| {
+            if let Some(theme) = Theme::by_name(&select.value()) {
+                settings.theme = theme;
+            }
+        },
+    );
+    let on_palette = on_setting(
+        settings,
+        on_change,
+        |settings, select: HtmlSelectElement| {
+            if let Some(palette) = Palette::by_name(&select.value()) {
+                settings.palette = palette;
+            }
+        },
+    );
+    let dialog = use_dialog_focus();
+    let on_close = on_close.clone();
+    html! {
+        <div class="settings-modal" ref={dialog} role="dialog" aria-label="settings" tabindex="-1">
+            <h3>{"settings"}</h3>
+            <div class="settings">
+                {checkbox(
+                    "hard mode",
+                    settings.hard_mode,
+                    on_setting(settings, on_change, |settings, input: HtmlInputElement| {
+                        settings.hard_mode = input.checked()
+                    }),
+                )}
+                <label>
+                    {"guesses"}
+                    <input
+                        type="number"
+                        min="1"
+                        max={MAX_GUESSES_LIMIT.to_string()}
+                        value={settings.max_guesses.to_string()}
+                        onchange={on_max_guesses}
+                    />
+                </label>
+                <label>
+                    {"word length"}
+                    <select class="toolbar-select" onchange={on_word_length}>
+                        {
+                            word_lengths.iter().map(|length| html! {
+                                <option value={length.to_string()} selected={*length == settings.word_length}>
+                                    {length}
+                                </option>
+                            }).collect::<Html>()
+                        }
+                    </select>
+                </label>
+                <label>
+                    {"language"}
+                    <select class="toolbar-select" onchange={on_language}>
+                        {
+                            LANGUAGES.iter().map(|option| html! {
+                                <option value={option.code} selected={option == language}>
+                                    {option.name}
+                                </option>
+                            }).collect::<Html>()
+                        }
+                    </select>
+                </label>
+                <label>
+                    {"keyboard"}
+                    <select class="toolbar-select" onchange={on_layout}>
+                        {
+                            LAYOUTS.iter().map(|layout| html! {
+                                <option value={layout.id} selected={layout.id == settings.keyboard.layout}>
+                                    {layout.name}
+                                </option>
+                            }).collect::<Html>()
+                        }
+                    </select>
+                </label>
+                {checkbox("ENTER on the right, ⌫ on the left", settings.keyboard.enter_on_right, on_enter_side)}
+                <label>
+                    {"theme"}
+                    <select class="toolbar-select" onchange={on_theme}>
+                        {
+                            Theme::ALL.iter().map(|theme| html! {
+                                <option value={theme.name()} selected={*theme == settings.theme}>
+                                    {theme.name()}
+                                </option>
+                            }).collect::<Html>()
+                        }
+                    </select>
+                </label>
+                <label>
+                    {"colors"}
+                    <select class="toolbar-select" onchange={on_palette}>
+                        {
+                            Palette::ALL.iter().map(|palette| html! {
+                                <option value={palette.name()} selected={*palette == settings.palette}>
+                                    {palette.name()}
+                                </option>
+                            }).collect::<Html>()
+                        }
+                    </select>
+                </label>
+                {checkbox(
+                    "hints",
+                    settings.hints,
+                    on_setting(settings, on_change, |settings, input: HtmlInputElement| {
+                        settings.hints = input.checked()
+                    }),
+                )}
+                {checkbox(
+                    "animations",
+                    settings.animations,
+                    on_setting(settings, on_change, |settings, input: HtmlInputElement| {
+                        settings.animations = input.checked()
+                    }),
+                )}
+            </div>
+            <button class="close-button" onclick={Callback::from(move |_| on_close.emit(()))}>
+                {"close"}
+            </button>
+        </div>
+    }
+}
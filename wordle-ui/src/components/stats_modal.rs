@@ -0,0 +1,96 @@
+use super::util::use_dialog_focus;
+use wordle_game::stats::Stats;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct StatsModalProps {
+    pub stats: Stats,
+    pub max_guesses: usize,
+    pub winning_guess_count: Option<usize>,
+    pub share_text: String,
+    pub on_new_game: Callback<MouseEvent>,
+    pub on_review: Callback<MouseEvent>,
+}
+
+#[function_component(StatsModal)]
+pub fn stats_modal(
+    StatsModalProps {
+        stats,
+        max_guesses,
+        winning_guess_count,
+        share_text,
+        on_new_game,
+        on_review,
+    }: &StatsModalProps,
+) -> Html {
+    let summary = [
+        (stats.games_played.to_string(), "played"),
+        (format!("{:.0}", stats.win_percentage()), "win %"),
+        (stats.current_streak.to_string(), "current streak"),
+        (stats.max_streak.to_string(), "max streak"),
+    ];
+    let scores = stats
+        .average_skill()
+        .zip(stats.average_luck())
+        .map(|(skill, luck)| {
+            html! {
+                <div class="stats-scores">
+                    {format!("average skill {:+.1} bits · luck {:+.1} bits", skill, luck)}
+                </div>
+            }
+        });
+    let distribution_rows = (*max_guesses).max(stats.guess_distribution.len());
+    let most_common = stats.guess_distribution.iter().max().copied().unwrap_or(0);
+    let dialog = use_dialog_focus();
+    html! {
+        <div class="stats-modal" ref={dialog} role="dialog" aria-label="statistics" tabindex="-1">
+            <h3>{"statistics"}</h3>
+            <div class="stats-summary">
+                {
+                    summary.into_iter().map(|(value, label)| html! {
+                        <div class="stats-summary-item">
+                            <div class="value">{value}</div>
+                            <div class="label">{label}</div>
+                        </div>
+                    }).collect::<Html>()
+                }
+            </div>
+            {scores.unwrap_or_default()}
+            <h3>{"guess distribution"}</h3>
+            <div class="guess-distribution">
+                {
+                    (0..distribution_rows).map(|i| {
+                        let count = stats.guess_distribution.get(i).copied().unwrap_or(0);
+                        let width = (count * 100).checked_div(most_common).unwrap_or(0).max(8);
+                        let is_last_game = *winning_guess_count == Some(i + 1);
+                        html! {
+                            <div class="distribution-row">
+                                <div class="guess-count">{i + 1}</div>
+                                <div
+                                    class={classes!("distribution-bar", is_last_game.then_some("highlight"))}
+                                    style={format!("width: {}%", width)}
+                                >
+                                    {count}
+                                </div>
+                            </div>
+                        }
+                    }).collect::<Html>()
+                }
+            </div>
+            <textarea
+                class="share-text"
+                readonly=true
+                rows={share_text.lines().count().to_string()}
+                value={share_text.clone()}
+            />
+            <div class="stats-actions">
+                <button class="review-button" onclick={on_review.clone()}>
+                    {"review"}
+                </button>
+                <button class="new-game-button" onclick={on_new_game.clone()}>
+                    {"new game"}
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -1,4 +1,15 @@
-use wordle_game::types::LetterState;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use wordle_game::share::{Palette, Theme};
+use wordle_game::types::{Guess, LetterState};
+use yew::{classes, use_effect_with_deps, use_node_ref, Classes, NodeRef};
+
+pub fn theme_classes(theme: Theme, palette: Palette) -> Classes {
+    classes!(
+        format!("theme-{}", theme.name()),
+        format!("palette-{}", palette.name())
+    )
+}
 
 pub fn letter_state_class(state: &LetterState) -> &'static str {
     use LetterState::*;
@@ -8,3 +19,50 @@ pub fn letter_state_class(state: &LetterState) -> &'static str {
         CorrectPlacement => "correct-placement",
     }
 }
+
+pub fn letter_state_label(state: &LetterState) -> &'static str {
+    use LetterState::*;
+    match state {
+        Incorrect => "not in the word",
+        CorrectLetter => "wrong position",
+        CorrectPlacement => "correct position",
+    }
+}
+
+pub fn letter_label(c: char, state: Option<&LetterState>) -> String {
+    let letter = c.to_uppercase().to_string();
+    match state {
+        Some(state) => format!("{}, {}", letter, letter_state_label(state)),
+        None => letter,
+    }
+}
+
+pub fn guess_label(guess: &Guess) -> String {
+    guess
+        .iter()
+        .map(|(c, state)| letter_label(*c, Some(state)))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Focuses the returned ref's element when shown and gives the focus back when it's gone.
+pub fn use_dialog_focus() -> NodeRef {
+    let dialog = use_node_ref();
+    use_effect_with_deps(
+        |dialog| {
+            let previous = gloo_utils::document()
+                .active_element()
+                .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+            if let Some(dialog) = dialog.cast::<HtmlElement>() {
+                dialog.focus().ok();
+            }
+            move || {
+                if let Some(previous) = previous {
+                    previous.focus().ok();
+                }
+            }
+        },
+        dialog.clone(),
+    );
+    dialog
+}
@@ -1,15 +1,80 @@
+use super::letter_heatmap::LetterHeatmap;
+use web_sys::HtmlSelectElement;
+use wordle_game::letter_frequency::LetterFrequencies;
+use wordle_game::suggestions::{uncertainty, Suggestion, SuggestionMethod};
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct WordHintsProps {
-    pub remaining_words: Vec<String>,
+    /// Every remaining word, best guess first; `None` while still ranking.
+    pub suggestions: Option<Vec<Suggestion>>,
+    pub frequencies: LetterFrequencies,
+    pub method: SuggestionMethod,
+    pub on_method_change: Callback<SuggestionMethod>,
+    pub on_select: Callback<String>,
+}
+
+#[derive(Properties, PartialEq)]
+struct RankedHintsProps {
+    suggestions: Vec<Suggestion>,
+    frequencies: LetterFrequencies,
+    method: SuggestionMethod,
+    on_method_change: Callback<SuggestionMethod>,
+    on_select: Callback<String>,
 }
 
 #[function_component(WordHints)]
-pub fn word_hints(props: &WordHintsProps) -> Html {
+fn word_hints(props: &RankedHintsProps) -> Html {
+    let on_method = {
+        let on_method_change = props.on_method_change.clone();
+        Callback::from(move |e: Event| {
+            let name = e.target_unchecked_into::<HtmlSelectElement>().value();
+            if let Some(method) = SuggestionMethod::by_name(&name) {
+                on_method_change.emit(method);
+            }
+        })
+    };
     html! {
         <div class="word-hints-scroll-container">
-            {props.remaining_words.iter().map(|word| html! {<p>{word}</p>}).collect::<Html>()}
+            <div class="hints-summary">
+                {format!(
+                    "{} words · {:.1} bits of uncertainty",
+                    props.suggestions.len(),
+                    uncertainty(props.suggestions.len())
+                )}
+            </div>
+            <label class="hints-method">
+                {"rank by "}
+                <select onchange={on_method}>
+                    {
+                        SuggestionMethod::ALL.into_iter().map(|method| html! {
+                            <option value={method.name()} selected={method == props.method}>
+                                {method.name()}
+                            </option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </label>
+            <LetterHeatmap frequencies={props.frequencies.clone()}/>
+            <ol class="suggestions">
+                {
+                    props.suggestions.iter().map(|suggestion| {
+                        let on_select = props.on_select.clone();
+                        let word = suggestion.word.clone();
+                        html! {
+                            <li>
+                                <button
+                                    class="suggestion"
+                                    onclick={Callback::from(move |_| on_select.emit(word.clone()))}
+                                >
+                                    {&suggestion.word}
+                                </button>
+                                <span class="suggestion-score">{format!("{:.2}", suggestion.score)}</span>
+                            </li>
+                        }
+                    }).collect::<Html>()
+                }
+            </ol>
         </div>
     }
 }
@@ -19,10 +84,30 @@ pub fn word_hints_popover(props: &WordHintsProps) -> Html {
     let is_open = use_state(|| false);
 
     if *is_open {
+        let on_select = {
+            let is_open = is_open.clone();
+            let on_select = props.on_select.clone();
+            Callback::from(move |word: String| {
+                is_open.set(false);
+                on_select.emit(word);
+            })
+        };
+        let hints = match &props.suggestions {
+            Some(suggestions) => html! {
+                <WordHints
+                    suggestions={suggestions.clone()}
+                    frequencies={props.frequencies.clone()}
+                    method={props.method}
+                    on_method_change={props.on_method_change.clone()}
+                    {on_select}
+                />
+            },
+            None => html! { <div class="hints-summary">{"ranking…"}</div> },
+        };
         html! {
             <>
             <div class="word-hints-popover">
-                <WordHints remaining_words={props.remaining_words.clone()}/>
+                {hints}
                 <button
                     class="show-hints-button"
                     onclick={Callback::from(move |_| is_open.set(false))}
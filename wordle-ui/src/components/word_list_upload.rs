@@ -0,0 +1,132 @@
+use gloo::file::callbacks::{read_as_text, FileReader};
+use gloo::file::File;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct WordListUploadProps {
+    pub is_custom: bool,
+    /// Emits the answers text and, if one was picked, the guesses text.
+    pub on_upload: Callback<(String, Option<String>)>,
+    pub on_reset: Callback<()>,
+}
+
+pub enum WordListUploadMessage {
+    PickAnswers(Option<File>),
+    PickGuesses(Option<File>),
+    AnswersRead(String),
+    GuessesRead(String),
+    Upload,
+}
+
+pub struct WordListUpload {
+    answers: Option<String>,
+    guesses: Option<String>,
+    readers: Vec<FileReader>,
+}
+
+impl WordListUpload {
+    fn read(
+        &mut self,
+        ctx: &Context<Self>,
+        file: File,
+        on_read: fn(String) -> WordListUploadMessage,
+    ) {
+        let link = ctx.link().clone();
+        let name = file.name();
+        let reader = read_as_text(&file, move |result| match result {
+            Ok(text) => link.send_message(on_read(text)),
+            Err(err) => log::warn!("unable to read {}: {}", name, err),
+        });
+        self.readers.push(reader);
+    }
+}
+
+fn picked_file(e: Event) -> Option<File> {
+    let input: HtmlInputElement = e.target_unchecked_into();
+    input.files()?.get(0).map(File::from)
+}
+
+impl Component for WordListUpload {
+    type Message = WordListUploadMessage;
+
+    type Properties = WordListUploadProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            answers: None,
+            guesses: None,
+            readers: vec![],
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        use WordListUploadMessage::*;
+        match msg {
+            PickAnswers(file) => {
+                self.answers = None;
+                if let Some(file) = file {
+                    self.read(ctx, file, AnswersRead);
+                }
+            }
+            PickGuesses(file) => {
+                self.guesses = None;
+                if let Some(file) = file {
+                    self.read(ctx, file, GuessesRead);
+                }
+            }
+            AnswersRead(text) => self.answers = Some(text),
+            GuessesRead(text) => self.guesses = Some(text),
+            Upload => {
+                if let Some(answers) = self.answers.clone() {
+                    ctx.props().on_upload.emit((answers, self.guesses.clone()));
+                }
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let on_reset = ctx.props().on_reset.clone();
+        html! {
+            <div class="word-list-upload">
+                <label>
+                    {"answers"}
+                    <input
+                        type="file"
+                        accept=".txt,text/plain"
+                        onchange={ctx.link().callback(|e| WordListUploadMessage::PickAnswers(picked_file(e)))}
+                    />
+                </label>
+                <label>
+                    {"allowed guesses (optional)"}
+                    <input
+                        type="file"
+                        accept=".txt,text/plain"
+                        onchange={ctx.link().callback(|e| WordListUploadMessage::PickGuesses(picked_file(e)))}
+                    />
+                </label>
+                <div class="word-list-actions">
+                    <button
+                        class="toolbar-button"
+                        disabled={self.answers.is_none()}
+                        onclick={ctx.link().callback(|_| WordListUploadMessage::Upload)}
+                    >
+                        {"use word list"}
+                    </button>
+                    {
+                        if ctx.props().is_custom {
+                            html! {
+                                <button class="toolbar-button" onclick={Callback::from(move |_| on_reset.emit(()))}>
+                                    {"use built-in words"}
+                                </button>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use wordle_game::language::Language;
+
+pub struct KeyboardLayout {
+    pub id: &'static str,
+    pub name: &'static str,
+    rows: LayoutRows,
+}
+
+enum LayoutRows {
+    Language,
+    Alphabetical,
+    Fixed(&'static [&'static str]),
+}
+
+pub const DEFAULT_LAYOUT: &str = "language";
+
+const ALPHABETICAL_ROW_LENGTH: usize = 10;
+
+pub static LAYOUTS: &[KeyboardLayout] = &[
+    KeyboardLayout {
+        id: DEFAULT_LAYOUT,
+        name: "language default",
+        rows: LayoutRows::Language,
+    },
+    KeyboardLayout {
+        id: "qwerty",
+        name: "QWERTY",
+        rows: LayoutRows::Fixed(&["qwertyuiop", "asdfghjkl", "zxcvbnm"]),
+    },
+    KeyboardLayout {
+        id: "azerty",
+        name: "AZERTY",
+        rows: LayoutRows::Fixed(&["azertyuiop", "qsdfghjklm", "wxcvbn"]),
+    },
+    KeyboardLayout {
+        id: "qwertz",
+        name: "QWERTZ",
+        rows: LayoutRows::Fixed(&["qwertzuiop", "asdfghjkl", "yxcvbnm"]),
+    },
+    KeyboardLayout {
+        id: "dvorak",
+        name: "Dvorak",
+        rows: LayoutRows::Fixed(&["pyfgcrl", "aoeuidhtns", "qjkxbmwvz"]),
+    },
+    KeyboardLayout {
+        id: "alphabetical",
+        name: "alphabetical",
+        rows: LayoutRows::Alphabetical,
+    },
+];
+
+impl KeyboardLayout {
+    pub fn by_id(id: &str) -> &'static KeyboardLayout {
+        LAYOUTS
+            .iter()
+            .find(|layout| layout.id == id)
+            .unwrap_or(&LAYOUTS[0])
+    }
+
+    /// Letters missing from a fixed layout, like `ñ` on QWERTY, get a row of their own.
+    pub fn rows(&self, language: &Language) -> Vec<String> {
+        let mut rows: Vec<String> = match self.rows {
+            LayoutRows::Language => language
+                .keyboard
+                .iter()
+                .map(|row| row.to_string())
+                .collect(),
+            LayoutRows::Alphabetical => {
+                let letters: Vec<char> = language.alphabet.chars().collect();
+                return letters
+                    .chunks(ALPHABETICAL_ROW_LENGTH)
+                    .map(|row| row.iter().collect())
+                    .collect();
+            }
+            LayoutRows::Fixed(rows) => rows.iter().map(|row| row.to_string()).collect(),
+        };
+        let missing: String = language
+            .alphabet
+            .chars()
+            .filter(|c| !rows.iter().any(|row| row.contains(*c)))
+            .collect();
+        if !missing.is_empty() {
+            rows.push(missing);
+        }
+        rows
+    }
+}
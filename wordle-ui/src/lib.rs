@@ -0,0 +1,3 @@
+//! Shared between the app and its solver worker, which Trunk builds as a
+//! separate binary.
+pub mod solver_worker;
@@ -1,4 +1,6 @@
 mod components;
+mod keyboard_layout;
+mod storage;
 use components::app::App;
 
 fn main() {
@@ -0,0 +1,219 @@
+use gloo::worker::{HandlerId, Worker, WorkerScope};
+use serde::{Deserialize, Serialize};
+use wordle_game::analysis::{analyze_guesses, score_guesses, GameAnalysis};
+use wordle_game::constraint::word_matches;
+use wordle_game::game::WordleGame;
+use wordle_game::language::{Language, ENGLISH};
+use wordle_game::solver::{SolverStep, WordleSolver};
+use wordle_game::solver_strategy::lookahead::LookaheadConfig;
+use wordle_game::solver_strategy::{build_strategy, SolverStrategy};
+use wordle_game::stats::GameScores;
+use wordle_game::suggestions::{rank_suggestions, Suggestion, SuggestionMethod};
+use wordle_game::types::{GameCondition, GameRules, Guesses, WordleGameState};
+use wordle_game::word_list::WordList;
+
+/// Script Trunk builds `src/bin/worker.rs` to. Relative to the page, since
+/// the app is served from a subdirectory.
+pub const WORKER_PATH: &str = "worker.js";
+
+#[derive(Serialize, Deserialize)]
+pub enum SolverInput {
+    UseWordList {
+        language: String,
+        answers: Option<String>,
+        guesses: Option<String>,
+    },
+    Hints {
+        guesses: Guesses,
+        method: SuggestionMethod,
+    },
+    Review {
+        secret_word: String,
+        guesses: Vec<String>,
+        rules: GameRules,
+        strategy: String,
+    },
+    Watch {
+        secret_word: Option<String>,
+        strategy: String,
+        rules: GameRules,
+    },
+    Score {
+        secret_word: String,
+        guesses: Vec<String>,
+        rules: GameRules,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum SolverOutput {
+    Hints {
+        guesses: Guesses,
+        method: SuggestionMethod,
+        suggestions: Vec<Suggestion>,
+    },
+    Review {
+        strategy: String,
+        analysis: GameAnalysis,
+    },
+    Watch {
+        secret_word: String,
+        steps: Vec<SolverStep>,
+    },
+    Score {
+        secret_word: String,
+        scores: GameScores,
+    },
+    Error(String),
+}
+
+/// Runs the slower strategies off the main thread so they don't block typing.
+pub struct SolverWorker {
+    word_list: WordList,
+}
+
+impl SolverWorker {
+    fn hints(&self, guesses: Guesses, method: SuggestionMethod) -> SolverOutput {
+        let game_state = WordleGameState {
+            guesses: &guesses,
+            condition: GameCondition::Playing,
+            rules: GameRules::default(),
+        };
+        let candidates: Vec<&str> = self
+            .word_list
+            .answer_refs()
+            .into_iter()
+            .filter(|word| word_matches(word, &game_state))
+            .collect();
+        let suggestions = rank_suggestions(method, &candidates, &guesses);
+        SolverOutput::Hints {
+            guesses,
+            method,
+            suggestions,
+        }
+    }
+
+    fn review(
+        &self,
+        secret_word: &str,
+        guesses: &[String],
+        rules: GameRules,
+        strategy: &str,
+    ) -> SolverOutput {
+        let mut solver = match self.strategy(strategy) {
+            Ok(solver) => solver,
+            Err(err) => return SolverOutput::Error(err),
+        };
+        let guesses: Vec<&str> = guesses.iter().map(String::as_str).collect();
+        SolverOutput::Review {
+            strategy: strategy.to_string(),
+            analysis: analyze_guesses(
+                &guesses,
+                secret_word,
+                rules,
+                &self.word_list.answer_refs(),
+                &self.word_list.allowed_refs(),
+                solver.as_mut(),
+            ),
+        }
+    }
+
+    fn watch(&self, secret_word: Option<String>, strategy: &str, rules: GameRules) -> SolverOutput {
+        let mut strategy = match self.strategy(strategy) {
+            Ok(strategy) => strategy,
+            Err(err) => return SolverOutput::Error(err),
+        };
+        let mut game = match secret_word {
+            None => WordleGame::from_word_list_with_random_secret_word(&self.word_list),
+            Some(word) => match self.word_list.language().normalize(&word) {
+                Some(word) if self.word_list.answers().contains(&word) => {
+                    WordleGame::from_word_list(&self.word_list, &word)
+                }
+                _ => return SolverOutput::Error(format!("{} is not one of the answers", word)),
+            },
+        };
+        if let Err(err) = game
+            .set_hard_mode(rules.hard_mode)
+            .and_then(|_| game.set_max_guesses(rules.max_guesses))
+        {
+            return SolverOutput::Error(err.to_string());
+        }
+        let steps = WordleSolver::new(&mut game, strategy.as_mut())
+            .run_explained(&self.word_list.answer_refs());
+        SolverOutput::Watch {
+            secret_word: game.secret_word().to_string(),
+            steps,
+        }
+    }
+
+    fn strategy(&self, name: &str) -> Result<Box<dyn SolverStrategy>, String> {
+        build_strategy(name, &self.word_list, None, &LookaheadConfig::default())
+    }
+
+    fn use_word_list(
+        &mut self,
+        language: &str,
+        answers: Option<String>,
+        guesses: Option<String>,
+    ) -> Result<(), String> {
+        let language =
+            Language::by_code(language).ok_or_else(|| format!("unknown language {}", language))?;
+        self.word_list = match answers {
+            Some(answers) => WordList::parse(language, &answers, guesses.as_deref())
+                .map_err(|err| err.to_string())?,
+            None => language.word_list(),
+        };
+        Ok(())
+    }
+}
+
+impl Worker for SolverWorker {
+    type Message = ();
+    type Input = SolverInput;
+    type Output = SolverOutput;
+
+    fn create(_scope: &WorkerScope<Self>) -> Self {
+        Self {
+            word_list: ENGLISH.word_list(),
+        }
+    }
+
+    fn update(&mut self, _scope: &WorkerScope<Self>, _msg: Self::Message) {}
+
+    fn received(&mut self, scope: &WorkerScope<Self>, msg: Self::Input, id: HandlerId) {
+        let output = match msg {
+            SolverInput::UseWordList {
+                language,
+                answers,
+                guesses,
+            } => match self.use_word_list(&language, answers, guesses) {
+                Ok(()) => return,
+                Err(err) => SolverOutput::Error(err),
+            },
+            SolverInput::Hints { guesses, method } => self.hints(guesses, method),
+            SolverInput::Review {
+                secret_word,
+                guesses,
+                rules,
+                strategy,
+            } => self.review(&secret_word, &guesses, rules, &strategy),
+            SolverInput::Watch {
+                secret_word,
+                strategy,
+                rules,
+            } => self.watch(secret_word, &strategy, rules),
+            SolverInput::Score {
+                secret_word,
+                guesses,
+                rules,
+            } => {
+                let guesses: Vec<&str> = guesses.iter().map(String::as_str).collect();
+                SolverOutput::Score {
+                    scores: score_guesses(&guesses, &secret_word, rules, &self.word_list),
+                    secret_word,
+                }
+            }
+        };
+        scope.respond(id, output);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::keyboard_layout::DEFAULT_LAYOUT;
+use gloo::storage::{errors::StorageError, LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use wordle_game::game::DEFAULT_MAX_GUESSES;
+use wordle_game::language::{Language, ENGLISH};
+use wordle_game::save::SavedGame;
+use wordle_game::share::{Palette, Theme};
+use wordle_game::stats::{GameRecord, GameScores, StatsStore};
+use wordle_game::types::GameRules;
+
+const GAME_KEY: &str = "simpl:game";
+const RESULTS_KEY: &str = "simpl:results";
+const DAILY_PLAYED_KEY: &str = "simpl:daily-played";
+const WORD_LIST_KEY: &str = "simpl:word-list";
+const LANGUAGE_KEY: &str = "simpl:language";
+const SETTINGS_KEY: &str = "simpl:settings";
+pub const DEFAULT_WORD_LENGTH: usize = 5;
+pub const MAX_GUESSES_LIMIT: usize = 10;
+
+#[derive(Serialize, Deserialize)]
+pub struct StoredGame {
+    pub game: SavedGame,
+    pub puzzle_number: Option<u64>,
+    #[serde(default)]
+    pub challenge: bool,
+}
+
+/// The raw text of an uploaded word list, parsed again on load.
+#[derive(Serialize, Deserialize)]
+pub struct StoredWordList {
+    pub answers: String,
+    pub guesses: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyboardSettings {
+    pub layout: String,
+    pub enter_on_right: bool,
+}
+
+impl Default for KeyboardSettings {
+    fn default() -> Self {
+        Self {
+            layout: DEFAULT_LAYOUT.to_string(),
+            enter_on_right: false,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Rules only change for games without guesses yet.
+    pub hard_mode: bool,
+    pub max_guesses: usize,
+    pub theme: Theme,
+    pub palette: Palette,
+    pub keyboard: KeyboardSettings,
+    /// An uploaded word list is only played when its words have this
+    /// length, otherwise the built-in words are.
+    pub word_length: usize,
+    pub hints: bool,
+    pub animations: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            hard_mode: false,
+            max_guesses: DEFAULT_MAX_GUESSES,
+            theme: Theme::default(),
+            palette: Palette::default(),
+            keyboard: KeyboardSettings::default(),
+            word_length: DEFAULT_WORD_LENGTH,
+            hints: true,
+            animations: true,
+        }
+    }
+}
+
+impl Settings {
+    pub fn rules(&self) -> GameRules {
+        GameRules {
+            max_guesses: self.max_guesses,
+            hard_mode: self.hard_mode,
+        }
+    }
+}
+
+pub fn load_game() -> Option<StoredGame> {
+    LocalStorage::get(GAME_KEY).ok()
+}
+
+pub fn save_game(game: &StoredGame) {
+    set(GAME_KEY, game);
+}
+
+pub fn last_daily_played() -> Option<u64> {
+    LocalStorage::get(DAILY_PLAYED_KEY).ok()
+}
+
+pub fn set_last_daily_played(puzzle_number: u64) {
+    set(DAILY_PLAYED_KEY, puzzle_number);
+}
+
+pub fn load_word_list() -> Option<StoredWordList> {
+    LocalStorage::get(WORD_LIST_KEY).ok()
+}
+
+pub fn save_word_list(word_list: &StoredWordList) {
+    set(WORD_LIST_KEY, word_list);
+}
+
+pub fn clear_word_list() {
+    LocalStorage::delete(WORD_LIST_KEY);
+}
+
+pub fn language() -> &'static Language {
+    LocalStorage::get::<String>(LANGUAGE_KEY)
+        .ok()
+        .and_then(|code| Language::by_code(&code))
+        .unwrap_or(&ENGLISH)
+}
+
+pub fn set_language(language: &Language) {
+    set(LANGUAGE_KEY, language.code);
+}
+
+pub fn settings() -> Settings {
+    let mut settings: Settings = LocalStorage::get(SETTINGS_KEY).unwrap_or_default();
+    // stored by hand
+    settings.max_guesses = settings.max_guesses.clamp(1, MAX_GUESSES_LIMIT);
+    settings
+}
+
+pub fn set_settings(settings: &Settings) {
+    set(SETTINGS_KEY, settings);
+}
+
+fn set<T: Serialize>(key: &str, value: T) {
+    if let Err(err) = LocalStorage::set(key, value) {
+        log::warn!("unable to persist {}: {}", key, err);
+    }
+}
+
+pub struct LocalStatsStore;
+
+impl LocalStatsStore {
+    /// Fills in the scores of the last record, if it is `secret_word`'s and
+    /// still missing them.
+    pub fn add_scores(
+        &mut self,
+        secret_word: &str,
+        scores: GameScores,
+    ) -> Result<(), StorageError> {
+        let mut records = self.load()?;
+        match records.last_mut() {
+            Some(record) if record.secret_word == secret_word && record.scores.is_none() => {
+                record.scores = Some(scores);
+                LocalStorage::set(RESULTS_KEY, records)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl StatsStore for LocalStatsStore {
+    type Error = StorageError;
+
+    fn load(&self) -> Result<Vec<GameRecord>, Self::Error> {
+        match LocalStorage::get(RESULTS_KEY) {
+            Err(StorageError::KeyNotFound(_)) => Ok(vec![]),
+            records => records,
+        }
+    }
+
+    fn record(&mut self, record: GameRecord) -> Result<(), Self::Error> {
+        let mut records = self.load()?;
+        records.push(record);
+        LocalStorage::set(RESULTS_KEY, records)
+    }
+}